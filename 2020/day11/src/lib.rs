@@ -0,0 +1,246 @@
+use std::fmt::{Debug, Display};
+
+use utils::solution::Solution;
+use utils::{all_directions, Grid};
+
+use itertools::Itertools;
+
+type Vec2 = utils::Vec2<isize>;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Seat {
+    Empty,
+    Occupied,
+    Floor,
+}
+
+impl Seat {
+    /// Transform the seat according to the rules, based on the provided neighbors of this seat
+    fn transform(&self, neighbors: impl Iterator<Item = Self>, max_occupied: usize) -> Self {
+        match self {
+            Seat::Floor => Seat::Floor,
+            Seat::Empty => match neighbors.filter(|s| matches!(s, Seat::Occupied)).count() {
+                0 => Seat::Occupied,
+                _ => Seat::Empty,
+            },
+            Seat::Occupied => match neighbors.filter(|s| matches!(s, Seat::Occupied)).count() {
+                n if n < max_occupied => Seat::Occupied,
+                _ => Seat::Empty,
+            },
+        }
+    }
+}
+
+impl Display for Seat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Seat::Empty => 'L',
+            Seat::Occupied => '#',
+            Seat::Floor => '.',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+impl From<char> for Seat {
+    fn from(c: char) -> Self {
+        match c {
+            'L' => Seat::Empty,
+            '#' => Seat::Occupied,
+            '.' => Seat::Floor,
+            _ => panic!("Invalid seat character '{}'", c),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq)]
+pub struct Seats {
+    map: Grid<Seat>,
+}
+
+impl Seats {
+    /// Iterator over all direct neighbors of seat at pos
+    fn neighbors(&self, pos: Vec2) -> impl Iterator<Item = Seat> + '_ {
+        self.map.neighbors8(pos).map(move |p| self.map[p])
+    }
+
+    /// Iterator over all seats visible from pos at all 8 directions
+    fn visible_seats(&self, pos: Vec2) -> impl Iterator<Item = Seat> + '_ {
+        all_directions().flat_map(move |dir| {
+            itertools::iterate(pos + dir, move |&p| p + dir)
+                .map(move |p| self.map.get(p).copied())
+                .while_some()
+                .filter(|seat| *seat != Seat::Floor)
+                .take(1)
+        })
+    }
+
+    /// Iterator over all occupied seats
+    fn occupied_seats(&self) -> impl Iterator<Item = Seat> + '_ {
+        self.map
+            .values()
+            .copied()
+            .filter(|s| matches!(s, Seat::Occupied))
+    }
+
+    fn gen_next_generation(&self) -> Self {
+        Self {
+            map: Grid::from_fn(self.map.width(), self.map.height(), |pos| {
+                self.map[pos].transform(self.neighbors(pos), 4)
+            }),
+        }
+    }
+
+    fn gen_next_generation_v2(&self) -> Self {
+        Self {
+            map: Grid::from_fn(self.map.width(), self.map.height(), |pos| {
+                self.map[pos].transform(self.visible_seats(pos), 5)
+            }),
+        }
+    }
+}
+
+impl Debug for Seats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.map)
+    }
+}
+
+fn find_stable_generation(mut seats: Seats) -> Seats {
+    loop {
+        let next_gen_seats = seats.gen_next_generation();
+        if seats == next_gen_seats {
+            return seats;
+        }
+        seats = next_gen_seats
+    }
+}
+
+fn find_stable_generation_v2(mut seats: Seats) -> Seats {
+    loop {
+        let next_gen_seats = seats.gen_next_generation_v2();
+        if seats == next_gen_seats {
+            return seats;
+        }
+        seats = next_gen_seats
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 11;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input<'a> = Seats;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        Seats {
+            map: input.lines().collect(),
+        }
+    }
+
+    fn part1(seats: &Self::Input<'_>) -> Option<usize> {
+        Some(
+            find_stable_generation(seats.clone())
+                .occupied_seats()
+                .count(),
+        )
+    }
+
+    fn part2(seats: &Self::Input<'_>) -> Option<usize> {
+        Some(
+            find_stable_generation_v2(seats.clone())
+                .occupied_seats()
+                .count(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let data = [
+            "L.LL.LL.LL",
+            "LLLLLLL.LL",
+            "L.L.L..L..",
+            "LLLL.LL.LL",
+            "L.LL.LL.LL",
+            "L.LLLLL.LL",
+            "..L.L.....",
+            "LLLLLLLLLL",
+            "L.LLLLLL.L",
+            "L.LLLLL.LL",
+        ];
+
+        let data = data.iter().copied().collect();
+
+        assert_eq!(
+            find_stable_generation(Seats { map: data })
+                .occupied_seats()
+                .count(),
+            37
+        );
+    }
+
+    #[test]
+    fn test_visibile_seats() {
+        let data = [
+            ".......#.",
+            "...#.....",
+            ".#.......",
+            ".........",
+            "..#L....#",
+            "....#....",
+            ".........",
+            "#........",
+            "...#.....",
+        ];
+
+        let data = data.iter().copied().collect();
+
+        let seats = Seats { map: data };
+        assert_eq!(seats.visible_seats(Vec2 { x: 3, y: 4 }).count(), 8);
+        assert_eq!(seats.visible_seats(Vec2 { x: 0, y: 0 }).count(), 2);
+
+        let data = [
+            ".##.##.", "#.#.#.#", "##...##", "...L...", "##...##", "#.#.#.#", ".##.##.",
+        ];
+
+        let data = data.iter().copied().collect();
+
+        let seats = Seats { map: data };
+        assert_eq!(seats.visible_seats(Vec2 { x: 3, y: 3 }).count(), 0);
+    }
+
+    #[test]
+    fn test_part2() {
+        let data = [
+            "L.LL.LL.LL",
+            "LLLLLLL.LL",
+            "L.L.L..L..",
+            "LLLL.LL.LL",
+            "L.LL.LL.LL",
+            "L.LLLLL.LL",
+            "..L.L.....",
+            "LLLLLLLLLL",
+            "L.LLLLLL.L",
+            "L.LLLLL.LL",
+        ];
+
+        let data = data.iter().copied().collect();
+
+        assert_eq!(
+            find_stable_generation_v2(Seats { map: data })
+                .occupied_seats()
+                .count(),
+            26
+        );
+    }
+}
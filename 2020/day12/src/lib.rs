@@ -0,0 +1,175 @@
+use std::{num::ParseIntError, str::FromStr};
+
+use utils::solution::Solution;
+
+type Vec2 = utils::Vec2<isize>;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Order {
+    Move(Vec2),
+    Rotate(i32), // rotate by number of degrees counter-clockwise
+    Forward(isize),
+}
+
+impl FromStr for Order {
+    type Err = ParseIntError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let action_char = s.chars().next().unwrap();
+        let value = s[1..].parse::<isize>()?;
+
+        match action_char {
+            'F' => Ok(Order::Forward(value)),
+            'L' => Ok(Order::Rotate(value as i32)),
+            'R' => Ok(Order::Rotate(-(value as i32))),
+            'E' => Ok(Order::Move(Vec2::EAST * value)),
+            'N' => Ok(Order::Move(Vec2::NORTH * value)),
+            'W' => Ok(Order::Move(Vec2::WEST * value)),
+            'S' => Ok(Order::Move(Vec2::SOUTH * value)),
+            _ => panic!("Wrong action character"),
+        }
+    }
+}
+
+trait TakesOrder {
+    fn execute_order(&mut self, order: &Order);
+}
+
+/// A ship for part 1
+#[derive(Default, Debug)]
+struct Ship {
+    position: Vec2,
+    direction: Vec2,
+}
+
+impl Ship {
+    fn new() -> Self {
+        Ship {
+            position: Vec2::default(),
+            direction: Vec2::EAST,
+        }
+    }
+}
+
+impl TakesOrder for Ship {
+    fn execute_order(&mut self, order: &Order) {
+        match *order {
+            Order::Forward(val) => {
+                self.position += self.direction * val;
+            }
+            Order::Move(dir) => {
+                self.position += dir;
+            }
+            Order::Rotate(angle) => {
+                self.direction = self.direction.rotate(angle);
+            }
+        }
+    }
+}
+
+/// A ship for part 2
+#[derive(Default, Debug)]
+struct Ship2 {
+    position: Vec2,
+    waypoint: Vec2,
+}
+
+impl Ship2 {
+    fn new() -> Self {
+        Ship2 {
+            position: Vec2::default(),
+            waypoint: Vec2 { x: 10, y: 1 },
+        }
+    }
+}
+
+impl TakesOrder for Ship2 {
+    fn execute_order(&mut self, order: &Order) {
+        match *order {
+            Order::Forward(val) => {
+                self.position += self.waypoint * val;
+            }
+            Order::Move(dir) => {
+                self.waypoint += dir;
+            }
+            Order::Rotate(angle) => {
+                self.waypoint = self.waypoint.rotate(angle);
+            }
+        }
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 12;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input<'a> = Vec<Order>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.lines().map(|l| Order::from_str(l).unwrap()).collect()
+    }
+
+    fn part1(orders: &Self::Input<'_>) -> Option<usize> {
+        let mut ship = Ship::new();
+        for order in orders {
+            ship.execute_order(order);
+        }
+        Some(ship.position.manhattan_distance(Vec2::default()) as usize)
+    }
+
+    fn part2(orders: &Self::Input<'_>) -> Option<usize> {
+        let mut ship = Ship2::new();
+        for order in orders {
+            ship.execute_order(order);
+        }
+        Some(ship.position.manhattan_distance(Vec2::default()) as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_part1() {
+        let input = ["F10", "N3", "F7", "R90", "F11"];
+        let ship = input.iter().map(|&l| Order::from_str(l).unwrap()).fold(
+            Ship::new(),
+            |mut ship, order| {
+                ship.execute_order(&order);
+                ship
+            },
+        );
+        assert_eq!(
+            ship.position.manhattan_distance(Vec2::default()) as usize,
+            25
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = ["F10", "N3", "F7", "R90", "F11"];
+        let ship = input.iter().map(|&l| Order::from_str(l).unwrap()).fold(
+            Ship2::new(),
+            |mut ship, order| {
+                ship.execute_order(&order);
+                ship
+            },
+        );
+        assert_eq!(
+            ship.position.manhattan_distance(Vec2::default()) as usize,
+            286
+        );
+    }
+
+    #[test]
+    fn test_rotate_vec2() {
+        assert_eq!(Vec2 { x: 1, y: 0 }.rotate(90), Vec2 { x: 0, y: 1 });
+        assert_eq!(Vec2 { x: 1, y: 0 }.rotate(-90), Vec2 { x: 0, y: -1 });
+        assert_eq!(Vec2 { x: 1, y: 0 }.rotate(180), Vec2 { x: -1, y: 0 });
+        assert_eq!(Vec2 { x: 1, y: 0 }.rotate(-180), Vec2 { x: -1, y: 0 });
+    }
+}
@@ -0,0 +1,84 @@
+use utils::solution::Solution;
+
+struct PasswordValidator {
+    first: usize,
+    second: usize,
+    char: char,
+}
+
+fn parse(line: &str) -> (PasswordValidator, &str) {
+    let first_pos = line.find('-').unwrap();
+    let first = line[..first_pos].parse::<usize>().unwrap();
+    let second_end = line.find(char::is_whitespace).unwrap();
+    let second = line[first_pos + 1..second_end].parse::<usize>().unwrap();
+    let char_pos = line.find(':').unwrap() - 1;
+    let char = line.chars().nth(char_pos).unwrap();
+    let pass = &line[char_pos + 3..];
+
+    (
+        PasswordValidator {
+            first,
+            second,
+            char,
+        },
+        pass,
+    )
+}
+
+fn is_valid(line: &str) -> bool {
+    let (validator, pass) = parse(line);
+    let count = pass.chars().filter(|c| *c == validator.char).count();
+
+    count <= validator.second && count >= validator.first
+}
+
+fn is_valid2(line: &str) -> bool {
+    let (validator, pass) = parse(line);
+
+    let check1 = pass[validator.first - 1..].starts_with(validator.char);
+    let check2 = pass[validator.second - 1..].starts_with(validator.char);
+
+    (check1 && !check2) || (!check1 && check2)
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 2;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input<'a> = Vec<&'a str>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.lines().collect()
+    }
+
+    fn part1(lines: &Self::Input<'_>) -> Option<usize> {
+        Some(lines.iter().filter(|line| is_valid(line)).count())
+    }
+
+    fn part2(lines: &Self::Input<'_>) -> Option<usize> {
+        Some(lines.iter().filter(|line| is_valid2(line)).count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_part1() {
+        assert!(is_valid("1-3 a: abcde"));
+        assert!(!is_valid("1-3 b: cdefg"));
+        assert!(is_valid("2-9 c: ccccccccc"));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert!(is_valid2("1-3 a: abcde"));
+        assert!(!is_valid2("1-3 b: cdefg"));
+        assert!(!is_valid2("2-9 c: ccccccccc"));
+    }
+}
@@ -0,0 +1,64 @@
+use std::collections::BTreeSet;
+
+use utils::solution::Solution;
+
+static REQUIRED: &[&str] = &["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+
+fn count_valid(input: &str) -> usize {
+    let required_set: BTreeSet<String> = REQUIRED.iter().cloned().map(|x| x.into()).collect();
+    let mut passport = BTreeSet::<String>::new();
+
+    let mut valid = 0;
+
+    for line in input.lines() {
+        match line {
+            "" => {
+                if passport == required_set {
+                    valid += 1;
+                }
+                passport.clear();
+            }
+            l => {
+                passport.extend(
+                    l.split_whitespace()
+                        .map(|kv| Some(kv.split_once(':').map_or(kv, |x| x.0)))
+                        .filter_map(|key| match key {
+                            Some(k) if k != "cid" => Some(k.into()),
+                            _ => None,
+                        }),
+                );
+                // for kv in l.split_whitespace() {
+                //     if let Some(key) = kv.splitn(2, ":").next() {
+                //         if key != "cid" {
+                //             passport.insert(key.into());
+                //         }
+                //     }
+                // }
+            }
+        }
+    }
+    if passport == required_set {
+        valid += 1;
+    }
+    valid
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 4;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input<'a> = &'a str;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Option<usize> {
+        Some(count_valid(input))
+    }
+}
@@ -0,0 +1,203 @@
+//! Static control flow analysis of the boot code.
+//!
+//! Every instruction has exactly one successor, so the control flow graph is a set
+//! of chains and loops. The node right after the last instruction stands for termination.
+
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::ops::Range;
+
+use crate::parser::Instruction;
+
+/// Where the control goes after the instruction at `ip`, `None` if outside of the program
+/// (other than right after its end)
+fn successor_of(program: &[Instruction], ip: usize, instruction: Instruction) -> Option<usize> {
+    let offset = match instruction {
+        Instruction::Jmp(offset) => offset,
+        Instruction::Nop(_) | Instruction::Acc(_) => 1,
+    };
+    let target = ip as i64 + offset as i64;
+    (0..=program.len() as i64)
+        .contains(&target)
+        .then_some(target as usize)
+}
+
+/// A single jmp <-> nop flip that makes the program terminate
+#[derive(Debug, PartialEq)]
+pub struct Fix {
+    pub ip: usize,
+    pub original: Instruction,
+    pub patched: Instruction,
+    /// The instructions executed after the patched one until the program terminates
+    pub path: Vec<usize>,
+}
+
+impl Display for Fix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "replacing `{}` at {} with `{}` leads to the end: {}",
+            self.original, self.ip, self.patched, self.ip
+        )?;
+        for ip in &self.path {
+            write!(f, " -> {}", ip)?;
+        }
+        write!(f, " -> end")
+    }
+}
+
+pub struct Cfg {
+    program: Vec<Instruction>,
+    successors: Vec<Option<usize>>,
+}
+
+impl Cfg {
+    pub fn new(program: &[Instruction]) -> Self {
+        let successors = program
+            .iter()
+            .enumerate()
+            .map(|(ip, instruction)| successor_of(program, ip, *instruction))
+            .collect();
+        Self {
+            program: program.to_vec(),
+            successors,
+        }
+    }
+
+    /// The node standing for termination, right after the last instruction
+    pub fn end(&self) -> usize {
+        self.program.len()
+    }
+
+    pub fn successor(&self, ip: usize) -> Option<usize> {
+        self.successors.get(ip).copied().flatten()
+    }
+
+    /// Maximal runs of instructions that are only entered at the first one
+    /// and only left after the last one
+    pub fn basic_blocks(&self) -> Vec<Range<usize>> {
+        let mut leaders = vec![false; self.end() + 1];
+        leaders[0] = true;
+        for (ip, instruction) in self.program.iter().enumerate() {
+            if let Instruction::Jmp(_) = instruction {
+                leaders[ip + 1] = true;
+                if let Some(target) = self.successor(ip) {
+                    leaders[target] = true;
+                }
+            }
+        }
+        let starts: Vec<_> = (0..self.end()).filter(|ip| leaders[*ip]).collect();
+        starts
+            .iter()
+            .zip(starts.iter().skip(1).chain([self.end()].iter()))
+            .map(|(start, end)| *start..*end)
+            .collect()
+    }
+
+    /// The instructions executed when starting at `ip`, in order,
+    /// until the program leaves the program or loops
+    pub fn reachable_from(&self, mut ip: usize) -> Vec<usize> {
+        let mut seen = vec![false; self.end()];
+        let mut path = vec![];
+        while ip < self.end() && !seen[ip] {
+            seen[ip] = true;
+            path.push(ip);
+            match self.successor(ip) {
+                Some(next) => ip = next,
+                None => break,
+            }
+        }
+        path
+    }
+
+    /// Whether the program terminates when starting at each of the nodes, the end included
+    pub fn terminating(&self) -> Vec<bool> {
+        let mut predecessors = vec![vec![]; self.end() + 1];
+        for (ip, successor) in self.successors.iter().enumerate() {
+            if let Some(successor) = successor {
+                predecessors[*successor].push(ip);
+            }
+        }
+        let mut terminating = vec![false; self.end() + 1];
+        terminating[self.end()] = true;
+        let mut queue = VecDeque::from(vec![self.end()]);
+        while let Some(ip) = queue.pop_front() {
+            for &predecessor in &predecessors[ip] {
+                if !terminating[predecessor] {
+                    terminating[predecessor] = true;
+                    queue.push_back(predecessor);
+                }
+            }
+        }
+        terminating
+    }
+
+    /// Find the jmp or nop whose flip makes the program terminate, in linear time.
+    ///
+    /// Only the instructions executed by the unpatched program matter. Flipping one of
+    /// them works if it then goes to an instruction from which the end is reachable.
+    /// That path can't go through the flipped instruction, otherwise the unpatched
+    /// program would terminate as well.
+    pub fn find_fix(&self) -> Option<Fix> {
+        let terminating = self.terminating();
+        self.reachable_from(0).into_iter().find_map(|ip| {
+            let original = self.program[ip];
+            let patched = original.flipped()?;
+            let next = successor_of(&self.program, ip, patched)?;
+            if !terminating[next] {
+                return None;
+            }
+            let path = if next == self.end() {
+                vec![]
+            } else {
+                self.reachable_from(next)
+            };
+            Some(Fix {
+                ip,
+                original,
+                patched,
+                path,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_program;
+
+    const PROGRAM: [&str; 9] = [
+        "nop +0", "acc +1", "jmp +4", "acc +3", "jmp -3", "acc -99", "acc +1", "jmp -4", "acc +6",
+    ];
+
+    fn cfg() -> Cfg {
+        Cfg::new(&parse_program(&PROGRAM.join("\n")).unwrap())
+    }
+
+    #[test]
+    fn test_graph() {
+        let cfg = cfg();
+        assert_eq!(vec![0..1, 1..3, 3..5, 5..6, 6..8, 8..9], cfg.basic_blocks());
+        assert_eq!(vec![0, 1, 2, 6, 7, 3, 4], cfg.reachable_from(0));
+        let terminating: Vec<_> = (0..=9).filter(|ip| cfg.terminating()[*ip]).collect();
+        assert_eq!(vec![8, 9], terminating);
+
+        let cfg = Cfg::new(&parse_program("jmp +2\njmp -5").unwrap());
+        assert_eq!(None, cfg.successor(1));
+        assert_eq!(vec![0..1, 1..2], cfg.basic_blocks());
+    }
+
+    #[test]
+    fn test_find_fix() {
+        let fix = cfg().find_fix().unwrap();
+        assert_eq!((7, Instruction::Nop(-4)), (fix.ip, fix.patched));
+        assert_eq!(
+            "replacing `jmp -4` at 7 with `nop -4` leads to the end: 7 -> 8 -> end",
+            fix.to_string()
+        );
+
+        let looping = Cfg::new(&parse_program("acc +1\njmp -1\nacc +1\njmp -1").unwrap());
+        assert_eq!(None, looping.find_fix());
+    }
+}
@@ -1 +1,105 @@
+use cfg::Cfg;
+use parser::{parse_program, Instruction};
+use utils::solution::Solution;
+use vm::{Machine, OnRevisit, Outcome};
+
+pub mod cfg;
 pub mod parser;
+pub mod vm;
+
+/// Accumulator
+type Acc = i32;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 8;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input<'a> = Vec<Instruction>;
+    type Answer1 = Acc;
+    type Answer2 = Acc;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_program(input).unwrap_or_else(|e| panic!("Invalid program: {}", e))
+    }
+
+    fn part1(program: &Self::Input<'_>) -> Option<Acc> {
+        let mut machine = Machine::new(program.clone());
+        match machine.run(OnRevisit) {
+            Outcome::Halted => Some(machine.acc()),
+            _ => None,
+        }
+    }
+
+    fn part2(program: &Self::Input<'_>) -> Option<Acc> {
+        let fix = Cfg::new(program).find_fix()?;
+        let mut machine = Machine::new(program.clone());
+        machine.patch(fix.ip, fix.patched);
+        match machine.run(OnRevisit) {
+            Outcome::Terminated => Some(machine.acc()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Find the single jmp <-> nop patch that makes the program terminate by trying them all.
+    /// If successfull, returns IP at which program needs fixing and accumulator after the fixed program finished.
+    fn fix_program(program: &[Instruction]) -> Option<(usize, Acc)> {
+        let mut machine = Machine::new(program.to_vec()).with_trace();
+        if machine.run(OnRevisit) != Outcome::Halted {
+            return None;
+        }
+        // Only the instructions executed before looping can be the culprit
+        let candidates: Vec<_> = machine.trace().iter().map(|e| e.ip).collect();
+        let mut machine = Machine::new(program.to_vec());
+        for ip in candidates.into_iter().rev() {
+            let patch = match program[ip].flipped() {
+                Some(patch) => patch,
+                None => continue,
+            };
+            let original = machine.patch(ip, patch);
+            machine.reset();
+            if machine.run(OnRevisit) == Outcome::Terminated {
+                return Some((ip, machine.acc()));
+            }
+            machine.patch(ip, original);
+        }
+        None
+    }
+
+    #[test]
+    fn test_find_acc() {
+        let test_data = [
+            "nop +0", "acc +1", "jmp +4", "acc +3", "jmp -3", "acc -99", "acc +1", "jmp -4",
+            "acc +6",
+        ];
+        let program = parse_program(&test_data.join("\n")).unwrap();
+        assert_eq!(fix_program(&program), Some((7, 8)));
+        assert_eq!(7, Cfg::new(&program).find_fix().unwrap().ip);
+        assert_eq!(Some(8), Day8::part2(&program));
+    }
+
+    #[test]
+    fn test_cfg_matches_brute_force() {
+        let program = Day8::parse(Day8::INPUT);
+        let fix = Cfg::new(&program).find_fix().unwrap();
+        assert_eq!(
+            fix_program(&program),
+            Some((fix.ip, Day8::part2(&program).unwrap()))
+        );
+    }
+
+    #[test]
+    fn test_input_round_trip() {
+        let program = Day8::parse(Day8::INPUT);
+        assert_eq!(Day8::INPUT, parser::format_program(&program));
+        let labeled = parser::format_program_with_labels(&program);
+        assert_eq!(program, parse_program(&labeled).unwrap());
+    }
+}
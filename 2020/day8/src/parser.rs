@@ -1,3 +1,6 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Write};
+
 use nom::{
     branch::alt, bytes::complete::tag, character::streaming::space0, combinator::opt,
     error_position, sequence::tuple, IResult,
@@ -10,6 +13,27 @@ pub enum Instruction {
     Jmp(i32),
 }
 
+impl Instruction {
+    /// Swap a jmp for a nop and vice versa, keeping the argument
+    pub fn flipped(self) -> Option<Self> {
+        match self {
+            Instruction::Nop(arg) => Some(Instruction::Jmp(arg)),
+            Instruction::Jmp(arg) => Some(Instruction::Nop(arg)),
+            Instruction::Acc(_) => None,
+        }
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Instruction::Nop(arg) => write!(f, "nop {:+}", arg),
+            Instruction::Acc(arg) => write!(f, "acc {:+}", arg),
+            Instruction::Jmp(arg) => write!(f, "jmp {:+}", arg),
+        }
+    }
+}
+
 pub fn parse_instruction(input: &str) -> IResult<&str, Instruction> {
     let (tail, (opcode, _, _, arg)) = tuple((
         alt((tag("nop"), tag("acc"), tag("jmp"))),
@@ -29,6 +53,181 @@ pub fn parse_instruction(input: &str) -> IResult<&str, Instruction> {
     }
 }
 
+#[derive(Debug, PartialEq)]
+pub enum ErrorKind {
+    /// Not an instruction with a numeric or label argument
+    Instruction,
+    /// Something else after the instruction
+    Trailing,
+    DuplicateLabel(String),
+    UnknownLabel(String),
+    /// Only jmp and nop can refer to a label
+    LabelArgument,
+}
+
+/// Where and why parsing a program failed. The line and column are 1-based.
+#[derive(Debug, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ErrorKind,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+        match &self.kind {
+            ErrorKind::Instruction => write!(f, "expected an instruction"),
+            ErrorKind::Trailing => write!(f, "unexpected input after the instruction"),
+            ErrorKind::DuplicateLabel(label) => write!(f, "label `{}` is already defined", label),
+            ErrorKind::UnknownLabel(label) => write!(f, "label `{}` is not defined", label),
+            ErrorKind::LabelArgument => write!(f, "only jmp and nop can refer to a label"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A line of a program stripped of the comment and the label
+struct Line<'a> {
+    number: usize,
+    /// The whole line, to find columns in
+    text: &'a str,
+    label: Option<&'a str>,
+    instruction: &'a str,
+}
+
+impl<'a> Line<'a> {
+    fn error(&self, at: &str, kind: ErrorKind) -> ParseError {
+        ParseError {
+            line: self.number,
+            column: at.as_ptr() as usize - self.text.as_ptr() as usize + 1,
+            kind,
+        }
+    }
+}
+
+fn is_label(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn split_line(number: usize, text: &str) -> Line<'_> {
+    let code = text.split('#').next().unwrap().trim();
+    let (label, instruction) = match code.split_once(':') {
+        Some((label, rest)) if is_label(label.trim_end()) => (Some(label.trim_end()), rest.trim()),
+        _ => (None, code),
+    };
+    Line {
+        number,
+        text,
+        label,
+        instruction,
+    }
+}
+
+/// Parse a whole program, one instruction per line.
+///
+/// Besides the canonical syntax (see [`format_program`]), a line can have a comment
+/// starting with `#` and can start with a label like `loop:`, which names the next
+/// instruction. jmp and nop can refer to a label instead of an offset.
+pub fn parse_program(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let lines: Vec<_> = input
+        .lines()
+        .enumerate()
+        .map(|(idx, text)| split_line(idx + 1, text))
+        .collect();
+
+    let mut labels = HashMap::new();
+    let mut ip = 0;
+    for line in &lines {
+        if let Some(label) = line.label {
+            if labels.insert(label, ip).is_some() {
+                return Err(line.error(label, ErrorKind::DuplicateLabel(label.to_string())));
+            }
+        }
+        if !line.instruction.is_empty() {
+            ip += 1;
+        }
+    }
+
+    let mut program = vec![];
+    for line in lines.iter().filter(|line| !line.instruction.is_empty()) {
+        let ip = program.len() as i32;
+        let mut words = line.instruction.split_whitespace();
+        let (opcode, arg) = (words.next().unwrap(), words.next().unwrap_or_default());
+        let instruction = if is_label(arg) {
+            if let Some(tail) = words.next() {
+                return Err(line.error(tail, ErrorKind::Trailing));
+            }
+            let target = *labels
+                .get(arg)
+                .ok_or_else(|| line.error(arg, ErrorKind::UnknownLabel(arg.to_string())))?;
+            match opcode {
+                "jmp" => Instruction::Jmp(target - ip),
+                "nop" => Instruction::Nop(target - ip),
+                _ => return Err(line.error(arg, ErrorKind::LabelArgument)),
+            }
+        } else {
+            match parse_instruction(line.instruction) {
+                Ok(("", instruction)) => instruction,
+                Ok((tail, _)) => return Err(line.error(tail.trim_start(), ErrorKind::Trailing)),
+                Err(nom::Err::Error(e) | nom::Err::Failure(e)) => {
+                    return Err(line.error(e.input, ErrorKind::Instruction))
+                }
+                Err(nom::Err::Incomplete(_)) => {
+                    let end = &line.instruction[line.instruction.len()..];
+                    return Err(line.error(end, ErrorKind::Instruction));
+                }
+            }
+        };
+        program.push(instruction);
+    }
+    Ok(program)
+}
+
+/// One instruction per line in the canonical syntax, e.g. `jmp +4`
+pub fn format_program(program: &[Instruction]) -> String {
+    program.iter().map(|i| format!("{}\n", i)).collect()
+}
+
+/// Like [`format_program`], but jumps within the program go to labels
+/// named after the target, e.g. `jmp l7` and `l7:` before the 8th instruction
+pub fn format_program_with_labels(program: &[Instruction]) -> String {
+    let target = |ip: usize, offset: i32| {
+        let target = ip as i64 + offset as i64;
+        (0..=program.len() as i64)
+            .contains(&target)
+            .then_some(target as usize)
+    };
+    let mut targets = vec![false; program.len() + 1];
+    for (ip, instruction) in program.iter().enumerate() {
+        if let Instruction::Jmp(offset) = instruction {
+            if let Some(target) = target(ip, *offset) {
+                targets[target] = true;
+            }
+        }
+    }
+
+    let mut text = String::new();
+    for (ip, is_target) in targets.into_iter().enumerate() {
+        if is_target {
+            writeln!(text, "l{}:", ip).unwrap();
+        }
+        match program.get(ip) {
+            Some(Instruction::Jmp(offset)) if target(ip, *offset).is_some() => {
+                writeln!(text, "    jmp l{}", target(ip, *offset).unwrap()).unwrap()
+            }
+            Some(instruction) => writeln!(text, "    {}", instruction).unwrap(),
+            None => {}
+        }
+    }
+    text
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -57,4 +256,82 @@ mod tests {
         assert!(parse_instruction("jmp").is_err());
         assert!(parse_instruction("nop").is_err());
     }
+
+    const PROGRAM: &str = "\
+nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6
+";
+
+    #[test]
+    fn test_round_trip() {
+        let program = parse_program(PROGRAM).unwrap();
+        assert_eq!(9, program.len());
+        assert_eq!(PROGRAM, format_program(&program));
+
+        let labeled = format_program_with_labels(&program);
+        assert!(labeled.contains("l6:\n    acc +1\n    jmp l3\n"));
+        assert_eq!(program, parse_program(&labeled).unwrap());
+
+        let sloppy = "nop 0\n\n  acc   1 # comment\njmp +4\n";
+        assert_eq!(
+            "nop +0\nacc +1\njmp +4\n",
+            format_program(&parse_program(sloppy).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_labels() {
+        let program = "start: acc +1\n# skip\njmp end\nnop start\nend:";
+        assert_eq!(
+            Ok(vec![
+                Instruction::Acc(1),
+                Instruction::Jmp(2),
+                Instruction::Nop(-2)
+            ]),
+            parse_program(program)
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = |line, column, kind| Err(ParseError { line, column, kind });
+        assert_eq!(
+            error(2, 3, ErrorKind::Instruction),
+            parse_program("nop +0\n  mul +3")
+        );
+        assert_eq!(error(1, 5, ErrorKind::Instruction), parse_program("acc ?5"));
+        assert_eq!(error(1, 4, ErrorKind::Instruction), parse_program("acc"));
+        assert_eq!(error(1, 8, ErrorKind::Trailing), parse_program("acc +1 +2"));
+        assert_eq!(
+            error(1, 5, ErrorKind::UnknownLabel("nowhere".into())),
+            parse_program("jmp nowhere")
+        );
+        assert_eq!(
+            error(1, 10, ErrorKind::Trailing),
+            parse_program("a: jmp a a")
+        );
+        assert_eq!(
+            error(1, 5, ErrorKind::UnknownLabel("nowhere".into())),
+            parse_program("nop nowhere")
+        );
+        assert_eq!(
+            error(1, 5, ErrorKind::LabelArgument),
+            parse_program("acc a\na:")
+        );
+        assert_eq!(
+            error(2, 1, ErrorKind::DuplicateLabel("a".into())),
+            parse_program("a:\na: nop +0")
+        );
+        assert_eq!(
+            "line 3, column 1: expected an instruction",
+            parse_program("\n\nwat").unwrap_err().to_string()
+        );
+    }
 }
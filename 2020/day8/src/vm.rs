@@ -0,0 +1,249 @@
+//! A stepping interpreter of the handheld console's boot code.
+
+use std::collections::HashSet;
+use std::convert::TryFrom;
+
+use crate::parser::Instruction;
+
+/// An executed instruction along with the state it left the machine in
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Executed {
+    pub ip: usize,
+    pub instruction: Instruction,
+    pub acc: i32,
+}
+
+/// Why the machine stopped running
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Outcome {
+    /// Jumped right after the last instruction
+    Terminated,
+    /// Jumped anywhere else outside of the program
+    OutOfBounds(isize),
+    /// About to execute an instruction with a breakpoint
+    Breakpoint(usize),
+    /// Stopped by the halting policy
+    Halted,
+}
+
+/// Decides whether the machine should stop before executing the next instruction
+pub trait Halt {
+    fn halt(&mut self, machine: &Machine) -> bool;
+}
+
+impl<F: FnMut(&Machine) -> bool> Halt for F {
+    fn halt(&mut self, machine: &Machine) -> bool {
+        self(machine)
+    }
+}
+
+/// Halt before executing any instruction for the second time
+pub struct OnRevisit;
+
+impl Halt for OnRevisit {
+    fn halt(&mut self, machine: &Machine) -> bool {
+        machine.visits(machine.ip()) > 0
+    }
+}
+
+/// Halt after executing the given number of instructions
+pub struct StepLimit(pub usize);
+
+impl Halt for StepLimit {
+    fn halt(&mut self, machine: &Machine) -> bool {
+        machine.steps() >= self.0
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Machine {
+    program: Vec<Instruction>,
+    ip: isize,
+    acc: i32,
+    steps: usize,
+    /// How many times every instruction has been executed
+    visits: Vec<usize>,
+    breakpoints: HashSet<usize>,
+    /// Don't stop at the breakpoint the machine is paused at when resuming
+    paused: bool,
+    trace: Option<Vec<Executed>>,
+}
+
+impl Machine {
+    pub fn new(program: Vec<Instruction>) -> Self {
+        Self {
+            visits: vec![0; program.len()],
+            program,
+            ip: 0,
+            acc: 0,
+            steps: 0,
+            breakpoints: HashSet::new(),
+            paused: false,
+            trace: None,
+        }
+    }
+
+    /// Record every executed instruction from now on
+    pub fn with_trace(mut self) -> Self {
+        self.trace = Some(vec![]);
+        self
+    }
+
+    pub fn program(&self) -> &[Instruction] {
+        &self.program
+    }
+
+    /// Index of the next instruction to execute.
+    /// Panics if the machine jumped before the start of the program.
+    pub fn ip(&self) -> usize {
+        usize::try_from(self.ip).expect("IP is out of the program")
+    }
+
+    /// Accumulator
+    pub fn acc(&self) -> i32 {
+        self.acc
+    }
+
+    /// Number of executed instructions
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// How many times the instruction at `ip` has been executed
+    pub fn visits(&self, ip: usize) -> usize {
+        self.visits.get(ip).copied().unwrap_or_default()
+    }
+
+    /// Executed instructions, empty unless created [`Machine::with_trace`]
+    pub fn trace(&self) -> &[Executed] {
+        self.trace.as_deref().unwrap_or_default()
+    }
+
+    pub fn add_breakpoint(&mut self, ip: usize) {
+        self.breakpoints.insert(ip);
+    }
+
+    pub fn remove_breakpoint(&mut self, ip: usize) {
+        self.breakpoints.remove(&ip);
+    }
+
+    /// Replace the instruction at `ip`, returning the previous one
+    pub fn patch(&mut self, ip: usize, instruction: Instruction) -> Instruction {
+        std::mem::replace(&mut self.program[ip], instruction)
+    }
+
+    /// Start over, keeping the (patched) program and the breakpoints
+    pub fn reset(&mut self) {
+        self.ip = 0;
+        self.acc = 0;
+        self.steps = 0;
+        self.visits.iter_mut().for_each(|v| *v = 0);
+        self.paused = false;
+        if let Some(trace) = &mut self.trace {
+            trace.clear();
+        }
+    }
+
+    /// Why the machine can't execute the next instruction, if it can't
+    fn stopped(&self) -> Option<Outcome> {
+        match usize::try_from(self.ip) {
+            Ok(ip) if ip < self.program.len() => None,
+            Ok(ip) if ip == self.program.len() => Some(Outcome::Terminated),
+            _ => Some(Outcome::OutOfBounds(self.ip)),
+        }
+    }
+
+    /// Execute a single instruction, ignoring breakpoints.
+    /// Returns why it couldn't if the machine is already outside of the program.
+    pub fn step(&mut self) -> Result<(), Outcome> {
+        if let Some(outcome) = self.stopped() {
+            return Err(outcome);
+        }
+        let ip = self.ip();
+        let instruction = self.program[ip];
+        match instruction {
+            Instruction::Nop(_) => self.ip += 1,
+            Instruction::Acc(arg) => {
+                self.acc += arg;
+                self.ip += 1
+            }
+            Instruction::Jmp(arg) => self.ip += arg as isize,
+        }
+        self.steps += 1;
+        self.visits[ip] += 1;
+        self.paused = false;
+        if let Some(trace) = &mut self.trace {
+            trace.push(Executed {
+                ip,
+                instruction,
+                acc: self.acc,
+            });
+        }
+        Ok(())
+    }
+
+    /// Run until leaving the program, hitting a breakpoint or being halted by the policy.
+    /// Running again after a breakpoint resumes past it.
+    pub fn run(&mut self, mut policy: impl Halt) -> Outcome {
+        loop {
+            if let Some(outcome) = self.stopped() {
+                return outcome;
+            }
+            if policy.halt(self) {
+                return Outcome::Halted;
+            }
+            let ip = self.ip();
+            if !self.paused && self.breakpoints.contains(&ip) {
+                self.paused = true;
+                return Outcome::Breakpoint(ip);
+            }
+            self.step().unwrap();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_program;
+
+    const PROGRAM: [&str; 9] = [
+        "nop +0", "acc +1", "jmp +4", "acc +3", "jmp -3", "acc -99", "acc +1", "jmp -4", "acc +6",
+    ];
+
+    #[test]
+    fn test_run() {
+        let mut machine = Machine::new(parse_program(&PROGRAM.join("\n")).unwrap()).with_trace();
+        assert_eq!(Outcome::Halted, machine.run(OnRevisit));
+        assert_eq!((1, 5), (machine.ip(), machine.acc()));
+        let ips: Vec<_> = machine.trace().iter().map(|e| e.ip).collect();
+        assert_eq!(vec![0, 1, 2, 6, 7, 3, 4], ips);
+
+        machine.reset();
+        assert_eq!(Outcome::Halted, machine.run(StepLimit(3)));
+        assert_eq!((6, 3), (machine.ip(), machine.steps()));
+
+        machine.patch(7, Instruction::Nop(-4));
+        machine.reset();
+        assert_eq!(Outcome::Terminated, machine.run(OnRevisit));
+        assert_eq!(8, machine.acc());
+        assert_eq!(Err(Outcome::Terminated), machine.step());
+
+        let mut machine = Machine::new(vec![Instruction::Jmp(-1)]);
+        assert_eq!(Outcome::OutOfBounds(-1), machine.run(OnRevisit));
+    }
+
+    #[test]
+    fn test_breakpoints() {
+        let mut machine = Machine::new(parse_program(&PROGRAM.join("\n")).unwrap());
+        machine.add_breakpoint(3);
+        assert_eq!(Outcome::Breakpoint(3), machine.run(|_: &Machine| false));
+        assert_eq!(2, machine.acc());
+        // Resuming goes past the breakpoint and stops at it again in the loop
+        assert_eq!(Outcome::Breakpoint(3), machine.run(|_: &Machine| false));
+        assert_eq!(7, machine.acc());
+        machine.remove_breakpoint(3);
+        assert_eq!(Outcome::Halted, machine.run(|m: &Machine| m.acc() > 20));
+        assert_eq!(21, machine.acc());
+    }
+}
@@ -0,0 +1,125 @@
+use std::collections::VecDeque;
+use std::fmt::Display;
+
+extern crate utils;
+use utils::solution::Solution;
+use utils::Grid;
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct Octopus(u32);
+
+impl Octopus {
+    fn load_energy(&mut self) -> bool {
+        self.0 += 1;
+        self.0 == 10
+    }
+
+    fn flashed(&self) -> bool {
+        self.0 > 9
+    }
+}
+
+impl From<char> for Octopus {
+    fn from(c: char) -> Self {
+        Self(c.to_digit(10).unwrap())
+    }
+}
+
+impl Display for Octopus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub type Map = Grid<Octopus>;
+
+fn step(map: &mut Map) -> usize {
+    let mut flashed = VecDeque::from_iter(map.positions());
+    let mut flash_cnt = 0;
+    while let Some(pos) = flashed.pop_front() {
+        if map[pos].load_energy() {
+            flash_cnt += 1;
+            flashed.extend(map.neighbors8(pos));
+        }
+    }
+
+    for octopus in map.values_mut().filter(|o| o.flashed()) {
+        octopus.0 = 0;
+    }
+
+    flash_cnt
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 11;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input<'a> = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        Map::from_iter(input.lines())
+    }
+
+    fn part1(map: &Self::Input<'_>) -> Option<usize> {
+        let mut map = map.clone();
+        Some((0..100).map(|_| step(&mut map)).sum())
+    }
+
+    fn part2(map: &Self::Input<'_>) -> Option<usize> {
+        let mut map = map.clone();
+        (1..).find(|_| step(&mut map) == map.width() * map.height())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_step() {
+        let input = ["11111", "19991", "19191", "19991", "11111"];
+        let mut map = Map::from_iter(input);
+
+        assert_eq!(9, step(&mut map));
+        println!("{}", map);
+
+        assert_eq!(0, step(&mut map));
+        println!("{}", map);
+    }
+
+    #[test]
+    fn test_step_large() {
+        let input = [
+            "5483143223",
+            "2745854711",
+            "5264556173",
+            "6141336146",
+            "6357385478",
+            "4167524645",
+            "2176841721",
+            "6882881134",
+            "4846848554",
+            "5283751526",
+        ];
+        let mut map = Map::from_iter(input);
+
+        let mut sum = 0;
+        for _ in 1..=10 {
+            sum += step(&mut map);
+            println!("{}", map);
+        }
+        assert_eq!(204, sum);
+        for s in 11..=200 {
+            let count = step(&mut map);
+            println!("After step {}, Flashes: {:?}", s, count);
+            println!("{}", map);
+            if s == 195 {
+                assert_eq!(100, count);
+            }
+        }
+    }
+}
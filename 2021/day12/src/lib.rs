@@ -0,0 +1,170 @@
+use std::collections::BTreeSet;
+
+use multimap::MultiMap;
+use utils::search;
+use utils::solution::Solution;
+
+#[derive(Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+pub enum Cave<'a> {
+    Small(&'a str),
+    Big(&'a str),
+    Start,
+    End,
+}
+
+impl Cave<'_> {
+    fn is_small(&self) -> bool {
+        matches!(self, Cave::Small(_))
+    }
+
+    fn is_end(&self) -> bool {
+        matches!(self, Cave::End)
+    }
+}
+
+impl<'a> From<&'a str> for Cave<'a> {
+    fn from(value: &'a str) -> Self {
+        match value {
+            "start" => Self::Start,
+            "end" => Self::End,
+            val if val.chars().all(char::is_lowercase) => Self::Small(val),
+            val if val.chars().all(char::is_uppercase) => Self::Big(val),
+            _ => unreachable!(),
+        }
+    }
+}
+
+pub struct CaveSystem<'a> {
+    map: MultiMap<Cave<'a>, Cave<'a>>,
+}
+
+fn parse_line(line: &str) -> (Cave<'_>, Cave<'_>) {
+    let mut splitted = line.split('-');
+    let from = Cave::from(splitted.next().unwrap());
+    let to = Cave::from(splitted.next().unwrap());
+    (from, to)
+}
+
+impl<'a> FromIterator<&'a str> for CaveSystem<'a> {
+    fn from_iter<T: IntoIterator<Item = &'a str>>(iter: T) -> Self {
+        let mut cave_system = MultiMap::<Cave, Cave>::from_iter(iter.into_iter().map(parse_line));
+
+        for (from, to) in cave_system.clone() {
+            for cave in to {
+                cave_system.entry(cave).or_insert_vec(vec![]).push(from);
+            }
+        }
+        Self { map: cave_system }
+    }
+}
+
+/// Where a path is and what it went through
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Path<'a> {
+    cave: Cave<'a>,
+    visited_small_caves: BTreeSet<Cave<'a>>,
+    /// One small cave may still be visited twice
+    can_revisit: bool,
+}
+
+impl<'a> CaveSystem<'a> {
+    fn next_paths(&self, path: &Path<'a>) -> Vec<Path<'a>> {
+        let mut next_paths = vec![];
+        for adjacent_cave in self.map.get_vec(&path.cave).unwrap() {
+            if *adjacent_cave == Cave::Start {
+                continue;
+            }
+            let mut new_path = path.clone();
+            new_path.cave = *adjacent_cave;
+            if adjacent_cave.is_small() && !new_path.visited_small_caves.insert(*adjacent_cave) {
+                if !path.can_revisit {
+                    continue;
+                }
+                new_path.can_revisit = false;
+            }
+            next_paths.push(new_path);
+        }
+        next_paths
+    }
+
+    fn count_paths_from_start(&self, can_revisit: bool) -> usize {
+        let start = Path {
+            cave: Cave::Start,
+            visited_small_caves: BTreeSet::new(),
+            can_revisit,
+        };
+        search::count_paths(
+            start,
+            |path| self.next_paths(path),
+            |path| path.cave.is_end(),
+        )
+    }
+
+    fn count_paths(&self) -> usize {
+        self.count_paths_from_start(false)
+    }
+
+    fn count_paths_v2(&self) -> usize {
+        self.count_paths_from_start(true)
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 12;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input<'a> = CaveSystem<'a>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        CaveSystem::from_iter(input.lines())
+    }
+
+    fn part1(cave_system: &Self::Input<'_>) -> Option<usize> {
+        Some(cave_system.count_paths())
+    }
+
+    fn part2(cave_system: &Self::Input<'_>) -> Option<usize> {
+        Some(cave_system.count_paths_v2())
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    #[test]
+    fn test_part1() {
+        let input = ["start-A", "start-b", "A-c", "A-b", "b-d", "A-end", "b-end"];
+        let cave_system = CaveSystem::from_iter(input);
+
+        println!("Cave system:");
+        for (k, v) in &cave_system.map {
+            println!("{:?} -> {:?}", k, v);
+        }
+
+        assert_eq!(10, cave_system.count_paths());
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = ["start-A", "start-b", "A-c", "A-b", "b-d", "A-end", "b-end"];
+        let cave_system = CaveSystem::from_iter(input);
+
+        assert_eq!(36, cave_system.count_paths_v2());
+    }
+    #[test]
+    fn test_part2_big() {
+        let input = [
+            "fs-end", "he-DX", "fs-he", "start-DX", "pj-DX", "end-zg", "zg-sl", "zg-pj", "pj-he",
+            "RW-he", "fs-DX", "pj-RW", "zg-RW", "start-pj", "he-WI", "zg-he", "pj-fs", "start-RW",
+        ];
+        let cave_system = CaveSystem::from_iter(input);
+
+        assert_eq!(3509, cave_system.count_paths_v2());
+    }
+}
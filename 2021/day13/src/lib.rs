@@ -0,0 +1,137 @@
+extern crate utils;
+use std::fmt::Display;
+
+use utils::solution::Solution;
+use utils::sparse_grid::Axis;
+use utils::{SparseGrid, Vec2};
+
+#[derive(Debug, Clone)]
+pub struct Page {
+    points: SparseGrid<bool>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Fold {
+    X(usize),
+    Y(usize),
+}
+
+impl<'a> From<&'a str> for Fold {
+    fn from(line: &'a str) -> Self {
+        let mut splitted = line.strip_prefix("fold along ").unwrap().split('=');
+        let axis = splitted.next().unwrap();
+        let val = splitted.next().unwrap().parse().unwrap();
+        match axis {
+            "x" => Fold::X(val),
+            "y" => Fold::Y(val),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl<'a> FromIterator<&'a str> for Page {
+    fn from_iter<T: IntoIterator<Item = &'a str>>(iter: T) -> Self {
+        Self {
+            points: SparseGrid::from_iter(iter.into_iter().map(|line| {
+                let mut splitted = line.split(',');
+                let pos = Vec2::<isize> {
+                    x: splitted.next().unwrap().parse().unwrap(),
+                    y: splitted.next().unwrap().parse().unwrap(),
+                };
+                (pos, true)
+            })),
+        }
+    }
+}
+
+impl Page {
+    fn fold(&mut self, at: Fold) {
+        self.points = match at {
+            Fold::X(val) => self.points.fold(Axis::X, val as isize),
+            Fold::Y(val) => self.points.fold(Axis::Y, val as isize),
+        };
+    }
+}
+
+fn parse_input<'a>(mut lines: impl Iterator<Item = &'a str>) -> (Page, Vec<Fold>) {
+    let page = Page::from_iter(lines.by_ref().take_while(|l| !l.is_empty()));
+    let folds = Vec::<Fold>::from_iter(lines.map(Fold::from));
+    (page, folds)
+}
+
+impl Display for Page {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let code = self.points.render(|dot| if *dot { '█' } else { ' ' });
+        write!(f, "{}", code)
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 13;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input<'a> = (Page, Vec<Fold>);
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_input(input.lines())
+    }
+
+    fn part1((page, folds): &Self::Input<'_>) -> Option<usize> {
+        let mut page = page.clone();
+        page.fold(*folds.first()?);
+        Some(page.points.len())
+    }
+
+    /// The code is rendered in a block of text, starting on a new line
+    fn part2((page, folds): &Self::Input<'_>) -> Option<String> {
+        let mut page = page.clone();
+        for fold in folds {
+            page.fold(*fold);
+        }
+        Some(format!("\n{}", page))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test() {
+        let input = [
+            "6,10",
+            "0,14",
+            "9,10",
+            "0,3",
+            "10,4",
+            "4,11",
+            "6,0",
+            "6,12",
+            "4,1",
+            "0,13",
+            "10,12",
+            "3,4",
+            "3,0",
+            "8,4",
+            "1,10",
+            "2,14",
+            "8,10",
+            "9,0",
+            "",
+            "fold along y=7",
+            "fold along x=5",
+        ];
+        let (mut page, folds) = parse_input(input.into_iter());
+
+        println!("{:?}", page);
+        println!("Folds: {:?}", folds);
+
+        page.fold(*folds.first().unwrap());
+        println!("{:?}", page);
+        assert_eq!(17, page.points.len());
+    }
+}
@@ -0,0 +1,288 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+use std::iter::once;
+
+use utils::solution::Solution;
+
+/// What gets inserted between the elements of a pair, one or more elements.
+/// Pairs without a rule don't grow.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Rules {
+    insertions: HashMap<(char, char), Vec<char>>,
+}
+
+impl Rules {
+    /// The pair with the elements to insert between, like `('C', 'H')` and `"B"`
+    pub fn insert(&mut self, pair: (char, char), elements: &str) {
+        self.insertions.insert(pair, elements.chars().collect());
+    }
+
+    /// The elements the pair turns into after one step, including the pair itself
+    fn grown(&self, (a, b): (char, char)) -> Vec<char> {
+        let inserted = self.insertions.get(&(a, b)).into_iter().flatten();
+        once(a).chain(inserted.copied()).chain(once(b)).collect()
+    }
+}
+
+fn parse_input<'a>(lines: impl IntoIterator<Item = &'a str>) -> (&'a str, Rules) {
+    let mut lines = lines.into_iter();
+    let polymer = lines.next().unwrap();
+
+    let mut rules = Rules::default();
+    for line in lines.skip(1) {
+        let (pair, elements) = line.split_once(" -> ").unwrap();
+        let mut chars = pair.chars();
+        let a = chars.next().unwrap();
+        let b = chars.next().unwrap();
+        assert_eq!(None, chars.next(), "Not a pair: {}", pair);
+        rules.insert((a, b), elements);
+    }
+
+    (polymer, rules)
+}
+
+/// The number of every element in the polymer after a step
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Histogram {
+    pub step: usize,
+    pub counts: BTreeMap<char, u128>,
+}
+
+impl Histogram {
+    /// Count the elements of the polymer itself
+    pub fn new(step: usize, polymer: &str) -> Self {
+        let mut counts = BTreeMap::new();
+        for c in polymer.chars() {
+            *counts.entry(c).or_default() += 1;
+        }
+        Self { step, counts }
+    }
+
+    pub fn most_minus_least_common(&self) -> Option<u128> {
+        let most = self.counts.values().max()?;
+        let least = self.counts.values().min()?;
+        Some(most - least)
+    }
+}
+
+/// The counts no longer fit in a `u128` at the step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow {
+    pub step: usize,
+}
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "the polymer is too long to count after step {}",
+            self.step
+        )
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// Add the count to the total, `None` if it overflows
+fn add(total: &mut u128, count: u128) -> Option<()> {
+    *total = total.checked_add(count)?;
+    Some(())
+}
+
+/// The number of every pair of adjacent elements in a polymer
+type PairCounts = HashMap<(char, char), u128>;
+
+/// The histograms of a growing polymer, starting with the one before the first step.
+/// Only the number of every pair of adjacent elements is kept, so it takes as long
+/// to get to a step as the step number, no matter how long the polymer is.
+/// Ends after the first step whose counts overflow.
+#[derive(Debug, Clone)]
+pub struct Growth<'a> {
+    rules: &'a Rules,
+    pairs: PairCounts,
+    /// The histogram to yield next, `None` once the counts overflowed
+    next: Option<Result<Histogram, Overflow>>,
+}
+
+pub fn grow<'a>(polymer: &str, rules: &'a Rules) -> Growth<'a> {
+    let mut pairs = PairCounts::new();
+    let chars: Vec<_> = polymer.chars().collect();
+    for pair in chars.windows(2) {
+        *pairs.entry((pair[0], pair[1])).or_default() += 1;
+    }
+    Growth {
+        rules,
+        pairs,
+        next: Some(Ok(Histogram::new(0, polymer))),
+    }
+}
+
+impl Growth<'_> {
+    /// The pairs and the histogram after a step from the histogram,
+    /// `None` if the counts overflow
+    fn step(&self, histogram: &Histogram) -> Option<(PairCounts, Histogram)> {
+        let mut pairs = HashMap::new();
+        let mut counts = histogram.counts.clone();
+        for (pair, count) in &self.pairs {
+            let grown = self.rules.grown(*pair);
+            for new_pair in grown.windows(2) {
+                add(pairs.entry((new_pair[0], new_pair[1])).or_default(), *count)?;
+            }
+            for element in &grown[1..grown.len() - 1] {
+                add(counts.entry(*element).or_default(), *count)?;
+            }
+        }
+        let step = histogram.step + 1;
+        Some((pairs, Histogram { step, counts }))
+    }
+}
+
+impl Iterator for Growth<'_> {
+    type Item = Result<Histogram, Overflow>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next.take()?;
+        if let Ok(histogram) = &current {
+            self.next = Some(match self.step(histogram) {
+                Some((pairs, next)) => {
+                    self.pairs = pairs;
+                    Ok(next)
+                }
+                None => Err(Overflow {
+                    step: histogram.step + 1,
+                }),
+            });
+        }
+        Some(current)
+    }
+}
+
+/// The polymer itself after the steps. It about doubles in length with every step,
+/// so this is only feasible for a few of them.
+pub fn expand(polymer: &str, rules: &Rules, steps: usize) -> String {
+    let mut polymer: Vec<char> = polymer.chars().collect();
+    for _ in 0..steps {
+        let mut grown: Vec<char> = polymer.first().into_iter().copied().collect();
+        for pair in polymer.windows(2) {
+            grown.extend(&rules.grown((pair[0], pair[1]))[1..]);
+        }
+        polymer = grown;
+    }
+    polymer.into_iter().collect()
+}
+
+fn most_minus_least_common(polymer: &str, rules: &Rules, steps: usize) -> Option<u128> {
+    grow(polymer, rules)
+        .nth(steps)?
+        .ok()?
+        .most_minus_least_common()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 14;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input<'a> = (&'a str, Rules);
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_input(input.lines())
+    }
+
+    fn part1((polymer, insertion_rules): &Self::Input<'_>) -> Option<u128> {
+        most_minus_least_common(polymer, insertion_rules, 10)
+    }
+
+    fn part2((polymer, insertion_rules): &Self::Input<'_>) -> Option<u128> {
+        most_minus_least_common(polymer, insertion_rules, 40)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: [&str; 18] = [
+        "NNCB", "", "CH -> B", "HH -> N", "CB -> H", "NH -> C", "HB -> C", "HC -> B", "HN -> C",
+        "NN -> C", "BH -> H", "NC -> B", "NB -> B", "BN -> B", "BB -> N", "BC -> B", "CC -> N",
+        "CN -> C",
+    ];
+
+    fn most_and_least(histogram: Histogram) -> (u128, u128) {
+        let most = *histogram.counts.values().max().unwrap();
+        let least = *histogram.counts.values().min().unwrap();
+        (most, least)
+    }
+
+    #[test]
+    fn test_part1() {
+        let (polymer, insertion_rules) = parse_input(EXAMPLE);
+
+        let histogram = grow(polymer, &insertion_rules).nth(10).unwrap().unwrap();
+        assert_eq!(10, histogram.step);
+        assert_eq!((1749, 161), most_and_least(histogram));
+    }
+
+    #[test]
+    fn test_part2() {
+        let (polymer, insertion_rules) = parse_input(EXAMPLE);
+
+        let histogram = grow(polymer, &insertion_rules).nth(40).unwrap().unwrap();
+        assert_eq!((2192039569602, 3849876073), most_and_least(histogram));
+    }
+
+    #[test]
+    fn test_expand() {
+        let (polymer, insertion_rules) = parse_input(EXAMPLE);
+        assert_eq!("NCNBCHB", expand(polymer, &insertion_rules, 1));
+        assert_eq!(
+            "NBBNBNBBCCNBCNCCNBBNBBNBBBNBBNBBCBHCBHHNHCBBCBHCB",
+            expand(polymer, &insertion_rules, 4)
+        );
+
+        // The literal polymers and the pair counts agree
+        let (polymer, insertion_rules) = Day14::parse(Day14::INPUT);
+        for (steps, histogram) in grow(polymer, &insertion_rules).take(12).enumerate() {
+            let expanded = expand(polymer, &insertion_rules, steps);
+            assert_eq!(Histogram::new(steps, &expanded), histogram.unwrap());
+        }
+    }
+
+    #[test]
+    fn test_rules() {
+        // Pairs without a rule stay as they are
+        let (polymer, rules) = parse_input(["NNCB", "", "NN -> C", "CB -> HH"]);
+        assert_eq!("NCNCHHB", expand(polymer, &rules, 1));
+        assert_eq!("NCNCHHB", expand(polymer, &rules, 5));
+        for (steps, histogram) in grow(polymer, &rules).take(6).enumerate() {
+            let expanded = expand(polymer, &rules, steps);
+            assert_eq!(Histogram::new(steps, &expanded), histogram.unwrap());
+        }
+
+        // Longer insertions
+        let (polymer, rules) = parse_input(["AB", "", "AB -> BA", "BB -> A", "AA -> B"]);
+        assert_eq!("ABAB", expand(polymer, &rules, 1));
+        assert_eq!("ABABABAB", expand(polymer, &rules, 2));
+        for (steps, histogram) in grow(polymer, &rules).take(8).enumerate() {
+            let expanded = expand(polymer, &rules, steps);
+            assert_eq!(Histogram::new(steps, &expanded), histogram.unwrap());
+        }
+    }
+
+    #[test]
+    fn test_overflow() {
+        // The number of A's doubles minus one every step
+        let (polymer, rules) = parse_input(["AA", "", "AA -> A"]);
+        let histogram = grow(polymer, &rules).nth(127).unwrap().unwrap();
+        assert_eq!(Some(&((1 << 127) + 1)), histogram.counts.get(&'A'));
+        assert_eq!(
+            Some(Err(Overflow { step: 128 })),
+            grow(polymer, &rules).nth(128)
+        );
+        assert_eq!(129, grow(polymer, &rules).count());
+    }
+}
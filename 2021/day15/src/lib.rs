@@ -0,0 +1,131 @@
+extern crate utils;
+
+use utils::solution::Solution;
+use utils::{orthogonal_directions, search, Grid, Vec2};
+
+#[derive(Debug)]
+pub struct RiskMap<const N: usize> {
+    map: Grid<u32>,
+}
+
+impl<'a, const N: usize> FromIterator<&'a str> for RiskMap<N> {
+    fn from_iter<T: IntoIterator<Item = &'a str>>(iter: T) -> Self {
+        Self {
+            map: Grid::from_rows(
+                iter.into_iter()
+                    .map(|line| line.chars().map(|c| c.to_digit(10).unwrap())),
+            ),
+        }
+    }
+}
+
+impl<const N: usize> RiskMap<N> {
+    /// The same map, repeated M times in both directions
+    fn tiled<const M: usize>(&self) -> RiskMap<M> {
+        RiskMap {
+            map: self.map.clone(),
+        }
+    }
+
+    fn width(&self) -> usize {
+        self.map.width() * N
+    }
+    fn height(&self) -> usize {
+        self.map.height() * N
+    }
+
+    fn get(&self, pos: Vec2<isize>) -> Option<u32> {
+        if pos.x < 0 || pos.y < 0 {
+            return None;
+        }
+        if (pos.y as usize) < self.height() && (pos.x as usize) < self.width() {
+            let tile = self.map.tile(pos);
+            let risk = self.map.get_wrapped(pos) + tile.x as u32 + tile.y as u32;
+
+            Some(if risk > 9 { risk % 9 } else { risk })
+        } else {
+            None
+        }
+    }
+
+    fn surrounding_pos(&self, pos: Vec2<isize>) -> impl Iterator<Item = Vec2<isize>> {
+        orthogonal_directions().map(move |delta| pos + delta)
+    }
+
+    /// Navigate from the 'start' to the 'end' using Dijkstra algorithm
+    fn navigate(&self, start: Vec2<isize>, end: Vec2<isize>) -> Option<u32> {
+        let successors = |pos: &Vec2<isize>| {
+            self.surrounding_pos(*pos)
+                .filter_map(|pos| self.get(pos).map(|risk| (pos, risk)))
+                .collect::<Vec<_>>()
+        };
+        search::dijkstra(start, successors, |pos| *pos == end).map(|(_, risk)| risk)
+    }
+}
+
+/// Find the lowest total risk from the top left to the bottom right corner
+fn lowest_total_risk<const N: usize>(map: &RiskMap<N>) -> Option<u32> {
+    map.navigate(
+        Vec2::<isize> { x: 0, y: 0 },
+        Vec2::<isize> {
+            x: map.width() as isize - 1,
+            y: map.height() as isize - 1,
+        },
+    )
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 15;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input<'a> = RiskMap<1>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        RiskMap::<1>::from_iter(input.lines())
+    }
+
+    fn part1(map: &Self::Input<'_>) -> Option<u32> {
+        lowest_total_risk(map)
+    }
+
+    fn part2(map: &Self::Input<'_>) -> Option<u32> {
+        lowest_total_risk(&map.tiled::<5>())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: [&str; 10] = [
+        "1163751742",
+        "1381373672",
+        "2136511328",
+        "3694931569",
+        "7463417111",
+        "1319128137",
+        "1359912421",
+        "3125421639",
+        "1293138521",
+        "2311944581",
+    ];
+
+    #[test]
+    fn test_part1() {
+        let map = RiskMap::<1>::from_iter(INPUT);
+        let distance = map.navigate(Vec2::<isize> { x: 0, y: 0 }, Vec2::<isize> { x: 9, y: 9 });
+        assert_eq!(Some(40), distance);
+    }
+
+    #[test]
+    fn test_part2() {
+        let map = RiskMap::<5>::from_iter(INPUT);
+        let distance = map.navigate(Vec2::<isize> { x: 0, y: 0 }, Vec2::<isize> { x: 49, y: 49 });
+        assert_eq!(Some(315), distance);
+    }
+}
@@ -0,0 +1,143 @@
+//! Serializing packets back into hexadecimal transmissions.
+
+use crate::{Packet, PacketValue};
+
+/// How operator packets give the length of their sub-packets
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthType {
+    /// The total length of the sub-packets in bits, in 15 bits
+    Bits,
+    /// The number of the sub-packets, in 11 bits
+    SubPackets,
+}
+
+#[derive(Default)]
+struct BitWriter {
+    bits: Vec<bool>,
+}
+
+impl BitWriter {
+    /// Append the lowest `count` bits of `value`, the most significant first
+    fn push(&mut self, value: u128, count: usize) {
+        assert!(
+            count >= u128::BITS as usize || value >> count == 0,
+            "{} doesn't fit in {} bits",
+            value,
+            count
+        );
+        self.bits
+            .extend((0..count).rev().map(|bit| (value >> bit) & 1 == 1));
+    }
+
+    /// Hexadecimal digits, padded with zero bits to whole bytes
+    fn to_hex(&self) -> String {
+        let padding = (8 - self.bits.len() % 8) % 8;
+        let bits: Vec<_> = self
+            .bits
+            .iter()
+            .copied()
+            .chain([false; 8].into_iter().take(padding))
+            .collect();
+        bits.chunks(4)
+            .map(|nibble| {
+                let value = nibble.iter().fold(0, |acc, bit| (acc << 1) | *bit as u32);
+                char::from_digit(value, 16).unwrap().to_ascii_uppercase()
+            })
+            .collect()
+    }
+}
+
+impl Packet {
+    fn write(&self, writer: &mut BitWriter, length_type: LengthType) {
+        writer.push(self.header.version as u128, 3);
+        writer.push(self.header.id as u128, 3);
+        match &self.value {
+            PacketValue::Literal(value) => {
+                assert_eq!(4, self.header.id, "Only packets with ID 4 are literals");
+                let groups = ((u128::BITS - value.leading_zeros()) as usize).div_ceil(4);
+                for group in (0..groups.max(1)).rev() {
+                    writer.push((group > 0) as u128, 1);
+                    writer.push((value >> (group * 4)) & 0xF, 4);
+                }
+            }
+            PacketValue::Operator(packets) => {
+                assert_ne!(4, self.header.id, "Packets with ID 4 are literals");
+                assert!(!packets.is_empty(), "Operators need sub-packets");
+                let mut sub_packets = BitWriter::default();
+                for packet in packets {
+                    packet.write(&mut sub_packets, length_type);
+                }
+                match length_type {
+                    LengthType::Bits => {
+                        writer.push(0, 1);
+                        writer.push(sub_packets.bits.len() as u128, 15);
+                    }
+                    LengthType::SubPackets => {
+                        writer.push(1, 1);
+                        writer.push(packets.len() as u128, 11);
+                    }
+                }
+                writer.bits.extend(sub_packets.bits);
+            }
+        }
+    }
+
+    /// The transmission of the packet in hexadecimal, all operators giving the length
+    /// of their sub-packets the same way. Panics if a length doesn't fit its field.
+    pub fn encode(&self, length_type: LengthType) -> String {
+        let mut writer = BitWriter::default();
+        self.write(&mut writer, length_type);
+        writer.to_hex()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::decode;
+
+    #[test]
+    fn test_encode() {
+        let literal = Packet::literal(6, 2021);
+        assert_eq!("D2FE28", literal.encode(LengthType::Bits));
+        assert_eq!("1000", Packet::literal(0, 0).encode(LengthType::Bits));
+
+        let operator = Packet::operator(1, 6, vec![Packet::literal(6, 10), Packet::literal(2, 20)]);
+        assert_eq!("38006F45291200", operator.encode(LengthType::Bits));
+        let operator = Packet::operator(
+            7,
+            3,
+            vec![
+                Packet::literal(2, 1),
+                Packet::literal(4, 2),
+                Packet::literal(1, 3),
+            ],
+        );
+        assert_eq!("EE00D40C823060", operator.encode(LengthType::SubPackets));
+    }
+
+    fn packets() -> impl Strategy<Value = Packet> {
+        let literal = (0..8u8, any::<u128>()).prop_map(|(v, value)| Packet::literal(v, value));
+        literal.prop_recursive(4, 64, 8, |inner| {
+            // Comparisons take exactly two sub-packets
+            let operands = prop_oneof![
+                (0..4u8, prop::collection::vec(inner.clone(), 1..8)),
+                (5..8u8, prop::collection::vec(inner, 2)),
+            ];
+            (0..8u8, operands)
+                .prop_map(|(version, (id, packets))| Packet::operator(version, id, packets))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_round_trip(packet in packets(), sub_packets: bool) {
+            let length_type = if sub_packets { LengthType::SubPackets } else { LengthType::Bits };
+            let hex = packet.encode(length_type);
+            let decoded = decode(&hex).unwrap();
+            prop_assert_eq!(packet, decoded);
+        }
+    }
+}
@@ -0,0 +1,302 @@
+//! A small infix language compiled to packets, e.g. `1 + 2 * max(3, 4) < 9`.
+//!
+//! From the loosest binding:
+//! - `a < b`, `a > b`, `a == b`, which don't chain
+//! - `a + b + ...`
+//! - `a * b * ...`
+//! - numbers, parentheses and the calls `sum(...)`, `product(...)`, `min(...)`, `max(...)`,
+//!   `gt(a, b)`, `lt(a, b)` and `eq(a, b)`
+//!
+//! All packets get version 0.
+
+use std::fmt::Display;
+
+use crate::Packet;
+
+#[derive(Debug, PartialEq)]
+pub enum ErrorKind {
+    UnexpectedChar(char),
+    UnexpectedEnd,
+    /// Doesn't fit a literal
+    InvalidNumber(String),
+    UnknownFunction(String),
+    /// The function takes a different number of arguments
+    Arity {
+        name: String,
+        expected: usize,
+    },
+}
+
+/// Where and why the compilation failed. The column is 1-based.
+#[derive(Debug, PartialEq)]
+pub struct ParseError {
+    pub column: usize,
+    pub kind: ErrorKind,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "column {}: ", self.column)?;
+        match &self.kind {
+            ErrorKind::UnexpectedChar(c) => write!(f, "unexpected `{}`", c),
+            ErrorKind::UnexpectedEnd => write!(f, "unexpected end of the expression"),
+            ErrorKind::InvalidNumber(number) => write!(f, "{} is not a valid literal", number),
+            ErrorKind::UnknownFunction(name) => write!(f, "unknown function `{}`", name),
+            ErrorKind::Arity { name, expected } => {
+                write!(f, "`{}` takes {} arguments", name, expected)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The operator type IDs of [`Packet::eval`] and the number of operands, if it's fixed
+fn function(name: &str) -> Option<(u8, Option<usize>)> {
+    match name {
+        "sum" => Some((0, None)),
+        "product" => Some((1, None)),
+        "min" => Some((2, None)),
+        "max" => Some((3, None)),
+        "gt" => Some((5, Some(2))),
+        "lt" => Some((6, Some(2))),
+        "eq" => Some((7, Some(2))),
+        _ => None,
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    fn error(&self, column: usize, kind: ErrorKind) -> ParseError {
+        ParseError {
+            column: column + 1,
+            kind,
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.chars.get(self.pos).is_some_and(|c| c.is_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.chars.get(self.pos).copied()
+    }
+
+    fn unexpected(&mut self) -> ParseError {
+        match self.peek() {
+            Some(c) => self.error(self.pos, ErrorKind::UnexpectedChar(c)),
+            None => self.error(self.pos, ErrorKind::UnexpectedEnd),
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), ParseError> {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    /// Consume the operator if it's next
+    fn operator(&mut self, op: &str) -> bool {
+        self.skip_whitespace();
+        let matches = op
+            .chars()
+            .enumerate()
+            .all(|(idx, c)| self.chars.get(self.pos + idx) == Some(&c));
+        if matches {
+            self.pos += op.chars().count();
+        }
+        matches
+    }
+
+    /// A run of characters matching the predicate, starting at the current position
+    fn take_while(&mut self, predicate: impl Fn(&char) -> bool) -> String {
+        let start = self.pos;
+        while self.chars.get(self.pos).is_some_and(&predicate) {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    fn comparison(&mut self) -> Result<Packet, ParseError> {
+        let lhs = self.sum()?;
+        // `=` alone isn't an operator, leave it to be reported as unexpected
+        let id = if self.operator("<") {
+            6
+        } else if self.operator(">") {
+            5
+        } else if self.operator("==") {
+            7
+        } else {
+            return Ok(lhs);
+        };
+        let rhs = self.sum()?;
+        Ok(Packet::operator(0, id, vec![lhs, rhs]))
+    }
+
+    /// One or more operands joined by the operator into a single packet
+    fn chain(
+        &mut self,
+        op: &str,
+        id: u8,
+        operand: fn(&mut Self) -> Result<Packet, ParseError>,
+    ) -> Result<Packet, ParseError> {
+        let mut operands = vec![operand(self)?];
+        while self.operator(op) {
+            operands.push(operand(self)?);
+        }
+        if operands.len() == 1 {
+            Ok(operands.pop().unwrap())
+        } else {
+            Ok(Packet::operator(0, id, operands))
+        }
+    }
+
+    fn sum(&mut self) -> Result<Packet, ParseError> {
+        self.chain("+", 0, Self::product)
+    }
+
+    fn product(&mut self) -> Result<Packet, ParseError> {
+        self.chain("*", 1, Self::primary)
+    }
+
+    fn primary(&mut self) -> Result<Packet, ParseError> {
+        match self.peek() {
+            Some('(') => {
+                self.pos += 1;
+                let packet = self.comparison()?;
+                self.expect(')')?;
+                Ok(packet)
+            }
+            Some(c) if c.is_ascii_digit() => {
+                let start = self.pos;
+                let number = self.take_while(char::is_ascii_digit);
+                number
+                    .parse()
+                    .map(|value| Packet::literal(0, value))
+                    .map_err(|_| self.error(start, ErrorKind::InvalidNumber(number)))
+            }
+            Some(c) if c.is_ascii_alphabetic() => {
+                let start = self.pos;
+                let name = self.take_while(char::is_ascii_alphabetic);
+                let (id, arity) = function(&name)
+                    .ok_or_else(|| self.error(start, ErrorKind::UnknownFunction(name.clone())))?;
+                self.expect('(')?;
+                let mut args = vec![self.comparison()?];
+                while self.peek() == Some(',') {
+                    self.pos += 1;
+                    args.push(self.comparison()?);
+                }
+                self.expect(')')?;
+                if let Some(expected) = arity.filter(|arity| *arity != args.len()) {
+                    return Err(self.error(start, ErrorKind::Arity { name, expected }));
+                }
+                Ok(Packet::operator(0, id, args))
+            }
+            _ => Err(self.unexpected()),
+        }
+    }
+}
+
+/// Compile the expression to a packet evaluating to its value
+pub fn compile(text: &str) -> Result<Packet, ParseError> {
+    let mut parser = Parser {
+        chars: text.chars().collect(),
+        pos: 0,
+    };
+    let packet = parser.comparison()?;
+    match parser.peek() {
+        None => Ok(packet),
+        Some(_) => Err(parser.unexpected()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::decode;
+    use crate::encode::LengthType;
+
+    fn eval(text: &str) -> u128 {
+        compile(text).unwrap().eval().unwrap()
+    }
+
+    #[test]
+    fn test_compile() {
+        let packet = compile("1 + 2 * max(3, 4) < 9").unwrap();
+        assert_eq!(
+            "(lt (sum (lit 1) (product (lit 2) (max (lit 3) (lit 4)))) (lit 9))",
+            packet.to_string()
+        );
+        assert_eq!(Ok(0), packet.eval());
+
+        assert_eq!(6, eval("1+2+3"));
+        assert_eq!(
+            "(sum (lit 1) (lit 2) (lit 3))",
+            compile("1+2+3").unwrap().to_string()
+        );
+        assert_eq!(9, eval("(1 + 2) * 3"));
+        assert_eq!(1, eval("min(5, 1, 3) * 6 == 2 * 3"));
+        assert_eq!(1, eval("eq(sum(1, 3), product(2, 2))"));
+        assert_eq!(1, eval("gt(2, 1) * lt(1, 2)"));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let packet = compile("max(1, 20 * 3, 7) + (4 > 3) * 100").unwrap();
+        let hex = packet.encode(LengthType::Bits);
+        let decoded = decode(&hex).unwrap();
+        assert_eq!(packet, decoded);
+        assert_eq!(Ok(160), decoded.eval());
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = |column, kind| Err(ParseError { column, kind });
+        assert_eq!(error(5, ErrorKind::UnexpectedEnd), compile("1 + "));
+        assert_eq!(error(3, ErrorKind::UnexpectedChar('-')), compile("1 - 2"));
+        assert_eq!(
+            error(7, ErrorKind::UnexpectedChar('<')),
+            compile("1 < 2 < 3")
+        );
+        assert_eq!(error(3, ErrorKind::UnexpectedChar('=')), compile("1 = 2"));
+        assert_eq!(
+            error(9, ErrorKind::UnexpectedChar(')')),
+            compile("max(1 , )")
+        );
+        assert_eq!(
+            error(1, ErrorKind::UnknownFunction("avg".into())),
+            compile("avg(1, 2)")
+        );
+        assert_eq!(
+            error(
+                1,
+                ErrorKind::Arity {
+                    name: "lt".into(),
+                    expected: 2
+                }
+            ),
+            compile("lt(1, 2, 3)")
+        );
+        assert_eq!(
+            error(
+                2,
+                ErrorKind::InvalidNumber("9999999999999999999999999999999999999999".into())
+            ),
+            compile("(9999999999999999999999999999999999999999)")
+        );
+        assert_eq!(
+            "column 4: unexpected `)`",
+            compile("(1))").unwrap_err().to_string()
+        );
+    }
+}
@@ -0,0 +1,513 @@
+use std::fmt::Display;
+
+use stream::{BitReader, Encoding};
+
+use utils::solution::Solution;
+
+pub mod encode;
+pub mod expr;
+pub mod stream;
+
+#[derive(Debug, PartialEq)]
+pub struct PacketHeader {
+    version: u8,
+    id: u8,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Packet {
+    header: PacketHeader,
+    value: PacketValue,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PacketValue {
+    Literal(u128),
+    Operator(Vec<Packet>),
+}
+
+impl Packet {
+    pub fn literal(version: u8, value: u128) -> Self {
+        Self {
+            header: PacketHeader { version, id: 4 },
+            value: PacketValue::Literal(value),
+        }
+    }
+
+    pub fn operator(version: u8, id: u8, packets: Vec<Packet>) -> Self {
+        Self {
+            header: PacketHeader { version, id },
+            value: PacketValue::Operator(packets),
+        }
+    }
+
+    fn version_sum(&self) -> usize {
+        let sum = self.header.version as usize;
+
+        match &self.value {
+            PacketValue::Literal(_) => sum,
+            PacketValue::Operator(packets) => {
+                sum + packets
+                    .iter()
+                    .fold(0, |acc, packet| acc + packet.version_sum())
+            }
+        }
+    }
+
+    /// The value of the expression. Decoded packets always have valid operands,
+    /// so the errors come from packets built by hand and have no offset.
+    pub fn eval(&self) -> Result<u128, DecodeError> {
+        let packets = match &self.value {
+            PacketValue::Literal(val) => return Ok(*val),
+            PacketValue::Operator(packets) => packets,
+        };
+        if !matches!(self.header.id, 0..=3 | 5..=7) {
+            return Err(DecodeError::InvalidOperator { id: self.header.id });
+        }
+        check_operands(self.header.id, packets.len(), None)?;
+        let values = packets
+            .iter()
+            .map(Packet::eval)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(match self.header.id {
+            0 => values
+                .iter()
+                .try_fold(0u128, |acc, val| acc.checked_add(*val))
+                .ok_or(DecodeError::Overflow)?,
+            1 => values
+                .iter()
+                .try_fold(1u128, |acc, val| acc.checked_mul(*val))
+                .ok_or(DecodeError::Overflow)?,
+            2 => *values.iter().min().unwrap(),
+            3 => *values.iter().max().unwrap(),
+            5 => (values[0] > values[1]) as u128,
+            6 => (values[0] < values[1]) as u128,
+            7 => (values[0] == values[1]) as u128,
+            _ => unreachable!(),
+        })
+    }
+}
+
+/// Why a transmission couldn't be decoded or evaluated.
+/// Offsets are in bits from the start of the transmission.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The transmission ended inside the field starting at the offset
+    UnexpectedEnd { offset: usize },
+    /// Non-ASCII characters are reported as the replacement character
+    InvalidHex { offset: usize, found: char },
+    Io {
+        offset: usize,
+        kind: std::io::ErrorKind,
+    },
+    /// The literal packet at the offset doesn't fit in 128 bits
+    LiteralOverflow { offset: usize },
+    /// The sub-packets of the operator at the offset don't take the number of bits it gives
+    BadLength {
+        offset: usize,
+        expected: usize,
+        actual: usize,
+    },
+    /// An operator without sub-packets
+    NoOperands { offset: Option<usize> },
+    /// A comparison (IDs 5 to 7) without exactly two sub-packets
+    ComparisonArity {
+        offset: Option<usize>,
+        operands: usize,
+    },
+    /// A value computed by the evaluation doesn't fit in 128 bits
+    Overflow,
+    /// An operator with the type ID of literals or one that doesn't fit in 3 bits
+    InvalidOperator { id: u8 },
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let at = |f: &mut std::fmt::Formatter<'_>, offset: Option<usize>| match offset {
+            Some(offset) => write!(f, "bit {}: ", offset),
+            None => Ok(()),
+        };
+        match *self {
+            DecodeError::UnexpectedEnd { offset } => {
+                write!(f, "bit {}: unexpected end of the transmission", offset)
+            }
+            DecodeError::InvalidHex { offset, found } => {
+                write!(f, "bit {}: `{}` is not a hexadecimal digit", offset, found)
+            }
+            DecodeError::Io { offset, kind } => write!(f, "bit {}: {}", offset, kind),
+            DecodeError::LiteralOverflow { offset } => {
+                write!(f, "bit {}: the literal doesn't fit in 128 bits", offset)
+            }
+            DecodeError::BadLength {
+                offset,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "bit {}: the sub-packets take {} bits instead of {}",
+                offset, actual, expected
+            ),
+            DecodeError::NoOperands { offset } => {
+                at(f, offset)?;
+                write!(f, "operator without sub-packets")
+            }
+            DecodeError::ComparisonArity { offset, operands } => {
+                at(f, offset)?;
+                write!(f, "comparison with {} sub-packets instead of 2", operands)
+            }
+            DecodeError::Overflow => write!(f, "the value doesn't fit in 128 bits"),
+            DecodeError::InvalidOperator { id } => {
+                write!(f, "no operator with type ID {}", id)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// Check the number of sub-packets of the operator with the ID
+fn check_operands(id: u8, count: usize, offset: Option<usize>) -> Result<(), DecodeError> {
+    if count == 0 {
+        Err(DecodeError::NoOperands { offset })
+    } else if (5..=7).contains(&id) && count != 2 {
+        Err(DecodeError::ComparisonArity {
+            offset,
+            operands: count,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// S-expressions like `(sum (lit 1) (max (lit 2) (lit 3)))`.
+/// The alternate form adds the versions, like `(sum#4 (lit#1 1) ...)`.
+impl Display for Packet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self.header.id {
+            0 => "sum".to_string(),
+            1 => "product".to_string(),
+            2 => "min".to_string(),
+            3 => "max".to_string(),
+            4 => "lit".to_string(),
+            5 => "gt".to_string(),
+            6 => "lt".to_string(),
+            7 => "eq".to_string(),
+            other => format!("op{}", other),
+        };
+        write!(f, "({}", name)?;
+        if f.alternate() {
+            write!(f, "#{}", self.header.version)?;
+        }
+        match &self.value {
+            PacketValue::Literal(value) => write!(f, " {}", value)?,
+            PacketValue::Operator(packets) => {
+                for packet in packets {
+                    if f.alternate() {
+                        write!(f, " {:#}", packet)?;
+                    } else {
+                        write!(f, " {}", packet)?;
+                    }
+                }
+            }
+        }
+        write!(f, ")")
+    }
+}
+
+/// Decode the packet at the start of the hexadecimal transmission,
+/// ignoring the bits after it
+pub fn decode(hex: &str) -> Result<Packet, DecodeError> {
+    BitReader::new(hex.as_bytes(), Encoding::Hex).packet()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 16;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input<'a> = Packet;
+    type Answer1 = usize;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        decode(input.lines().next().unwrap())
+            .unwrap_or_else(|e| panic!("Failed to parse the packet: {}", e))
+    }
+
+    fn part1(packet: &Self::Input<'_>) -> Option<usize> {
+        Some(packet.version_sum())
+    }
+
+    fn part2(packet: &Self::Input<'_>) -> Option<u128> {
+        packet.eval().ok()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_bits() {
+        let input = "D2FE28";
+        let mut bits = BitReader::new(input.as_bytes(), Encoding::Hex);
+
+        assert_eq!(Ok(6), bits.read(3));
+        assert_eq!(Ok(4), bits.read(3));
+        assert_eq!(Ok(0b10111), bits.read(5));
+        assert_eq!(Ok(0b11110), bits.read(5));
+        assert_eq!(Ok(0b00101), bits.read(5));
+        assert_eq!(Ok(0), bits.read(3));
+        assert_eq!(Err(DecodeError::UnexpectedEnd { offset: 24 }), bits.read(1));
+    }
+
+    #[test]
+    fn test_parse_literal_packet() {
+        let input = "D2FE28";
+        let mut packets = BitReader::new(input.as_bytes(), Encoding::Hex).packets();
+        let packet = packets.next().unwrap().unwrap();
+        assert_eq!(
+            Packet {
+                header: PacketHeader { version: 6, id: 4 },
+                value: PacketValue::Literal(2021)
+            },
+            packet
+        );
+        assert_eq!(6, packet.version_sum());
+        assert_eq!(None, packets.next());
+    }
+
+    #[test]
+    fn test_parse_operator_packet() {
+        let input = "8A004A801A8002F478";
+        let mut packets = BitReader::new(input.as_bytes(), Encoding::Hex).packets();
+        let packet = packets.next().unwrap().unwrap();
+        assert_eq!(
+            Packet {
+                header: PacketHeader { version: 4, id: 2 },
+                value: PacketValue::Operator(vec![Packet {
+                    header: PacketHeader { version: 1, id: 2 },
+                    value: PacketValue::Operator(vec![Packet {
+                        header: PacketHeader { version: 5, id: 2 },
+                        value: PacketValue::Operator(vec![Packet {
+                            header: PacketHeader { version: 6, id: 4 },
+                            value: PacketValue::Literal(15)
+                        }])
+                    }])
+                }])
+            },
+            packet
+        );
+        assert_eq!(16, packet.version_sum());
+        assert_eq!(None, packets.next());
+
+        let input = "EE00D40C823060";
+        let packet = decode(input).unwrap();
+        assert_eq!(14, packet.version_sum());
+
+        let input = "620080001611562C8802118E34";
+        let packet = decode(input).unwrap();
+        assert_eq!(12, packet.version_sum());
+
+        let input = "C0015000016115A2E0802F182340";
+        let packet = decode(input).unwrap();
+        assert_eq!(23, packet.version_sum());
+
+        let input = "A0016C880162017C3686B18A3D4780";
+        let packet = decode(input).unwrap();
+        assert_eq!(31, packet.version_sum());
+    }
+
+    #[test]
+    fn test_add_packets() {
+        assert_eq!(
+            Ok(7),
+            Packet {
+                header: PacketHeader { version: 4, id: 0 },
+                value: PacketValue::Operator(vec![
+                    Packet {
+                        header: PacketHeader { version: 1, id: 2 },
+                        value: PacketValue::Literal(3)
+                    },
+                    Packet {
+                        header: PacketHeader { version: 1, id: 2 },
+                        value: PacketValue::Literal(4)
+                    }
+                ])
+            }
+            .eval()
+        );
+    }
+
+    #[test]
+    fn test_packets_prod() {
+        assert_eq!(
+            Ok(12),
+            Packet {
+                header: PacketHeader { version: 4, id: 1 },
+                value: PacketValue::Operator(vec![
+                    Packet {
+                        header: PacketHeader { version: 1, id: 2 },
+                        value: PacketValue::Literal(3)
+                    },
+                    Packet {
+                        header: PacketHeader { version: 1, id: 2 },
+                        value: PacketValue::Literal(4)
+                    }
+                ])
+            }
+            .eval()
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let packet = decode("9C0141080250320F1802104A08").unwrap();
+        assert_eq!(
+            "(eq (sum (lit 1) (lit 3)) (product (lit 2) (lit 2)))",
+            packet.to_string()
+        );
+        let packet = decode("C200B40A82").unwrap();
+        assert_eq!("(sum#6 (lit#6 1) (lit#2 2))", format!("{:#}", packet));
+    }
+
+    #[test]
+    fn test_part2() {
+        let packet = decode("C200B40A82").unwrap();
+        assert_eq!(Ok(3), packet.eval());
+
+        let packet = decode("04005AC33890").unwrap();
+        assert_eq!(Ok(54), packet.eval());
+
+        let packet = decode("880086C3E88112").unwrap();
+        assert_eq!(Ok(7), packet.eval());
+
+        let packet = decode("CE00C43D881120").unwrap();
+        assert_eq!(Ok(9), packet.eval());
+
+        let packet = decode("D8005AC2A8F0").unwrap();
+        assert_eq!(Ok(1), packet.eval());
+
+        let packet = decode("F600BC2D8F").unwrap();
+        assert_eq!(Ok(0), packet.eval());
+
+        let packet = decode("9C005AC2F8F0").unwrap();
+        assert_eq!(Ok(0), packet.eval());
+
+        let packet = decode("9C0141080250320F1802104A08").unwrap();
+        assert_eq!(Ok(1), packet.eval());
+    }
+
+    /// Hexadecimal digits of the bits, padded with zeros
+    fn hex(bits: &str) -> String {
+        let bits: Vec<_> = bits.chars().filter(|c| !c.is_whitespace()).collect();
+        bits.chunks(4)
+            .map(|nibble| {
+                let value = (0..4).fold(0, |acc, idx| {
+                    (acc << 1) | nibble.get(idx).map_or(0, |bit| bit.to_digit(2).unwrap())
+                });
+                char::from_digit(value, 16).unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        assert_eq!(
+            Err(DecodeError::UnexpectedEnd { offset: 16 }),
+            decode("D2FE")
+        );
+        assert_eq!(
+            Err(DecodeError::InvalidHex {
+                offset: 8,
+                found: 'X'
+            }),
+            decode("D2XE28")
+        );
+        assert_eq!(
+            Err(DecodeError::InvalidHex {
+                offset: 4,
+                found: char::REPLACEMENT_CHARACTER
+            }),
+            decode("Dé")
+        );
+        // sum with 5 bits of sub-packets, holding a literal of 11 bits
+        assert_eq!(
+            Err(DecodeError::BadLength {
+                offset: 0,
+                expected: 5,
+                actual: 11
+            }),
+            decode(&hex("000 000 0 000000000000101 000 100 00001"))
+        );
+        // the same nested in a product, with 10 bits of sub-packets
+        assert_eq!(
+            Err(DecodeError::BadLength {
+                offset: 18,
+                expected: 10,
+                actual: 11
+            }),
+            decode(&hex(
+                "000 001 1 00000000001 000 000 0 000000000001010 000 100 00001"
+            ))
+        );
+        assert_eq!(
+            Err(DecodeError::NoOperands { offset: Some(0) }),
+            decode(&hex("000 000 1 00000000000"))
+        );
+        assert_eq!(
+            Err(DecodeError::NoOperands { offset: Some(0) }),
+            decode(&hex("000 011 0 000000000000000"))
+        );
+        assert_eq!(
+            Err(DecodeError::ComparisonArity {
+                offset: Some(0),
+                operands: 1
+            }),
+            decode(&hex("000 101 1 00000000001 000 100 00001"))
+        );
+        // 33 groups of 4 bits
+        assert_eq!(
+            Err(DecodeError::LiteralOverflow { offset: 0 }),
+            decode(&hex(&format!("000 100 {} 0 0001", "1 0001 ".repeat(32))))
+        );
+        assert_eq!(
+            "bit 8: `X` is not a hexadecimal digit",
+            decode("D2XE28").unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_eval_errors() {
+        let lit = |value| Packet::literal(0, value);
+        assert_eq!(
+            Err(DecodeError::NoOperands { offset: None }),
+            Packet::operator(0, 2, vec![]).eval()
+        );
+        let lt = Packet::operator(0, 6, vec![lit(1), lit(2), lit(3)]);
+        let sum = Packet::operator(0, 0, vec![lit(1), lt]);
+        assert_eq!(
+            Err(DecodeError::ComparisonArity {
+                offset: None,
+                operands: 3
+            }),
+            sum.eval()
+        );
+        assert_eq!(
+            "comparison with 3 sub-packets instead of 2",
+            sum.eval().unwrap_err().to_string()
+        );
+        let product = Packet::operator(0, 1, vec![lit(u128::MAX), lit(2)]);
+        assert_eq!(Err(DecodeError::Overflow), product.eval());
+        for id in [4, 8, u8::MAX] {
+            let operator = Packet::operator(0, id, vec![lit(1), lit(2)]);
+            assert_eq!(Err(DecodeError::InvalidOperator { id }), operator.eval());
+        }
+        let nested = Packet::operator(0, 3, vec![Packet::operator(0, 4, vec![lit(1)])]);
+        assert_eq!(
+            "no operator with type ID 4",
+            nested.eval().unwrap_err().to_string()
+        );
+    }
+}
@@ -0,0 +1,317 @@
+//! Decoding packets from any reader of hexadecimal digits or raw bytes,
+//! without holding the whole transmission in memory.
+
+use std::io::{self, Read};
+
+use crate::{check_operands, DecodeError, Packet, PacketHeader, PacketValue};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    /// Hexadecimal digits, ignoring ASCII whitespace
+    Hex,
+    /// The bits themselves, the most significant first
+    Raw,
+}
+
+/// How operator packets give the length of their sub-packets
+enum Len {
+    Bits(usize),
+    SubPackets(usize),
+}
+
+/// Reads the transmission bit by bit, buffering the reader
+pub struct BitReader<R> {
+    bytes: io::Bytes<io::BufReader<R>>,
+    encoding: Encoding,
+    /// The last digit or byte read, of which the lowest `pending` bits weren't consumed
+    buffer: u8,
+    pending: u32,
+    offset: usize,
+}
+
+impl<R: Read> BitReader<R> {
+    pub fn new(reader: R, encoding: Encoding) -> Self {
+        Self {
+            bytes: io::BufReader::new(reader).bytes(),
+            encoding,
+            buffer: 0,
+            pending: 0,
+            offset: 0,
+        }
+    }
+
+    /// The number of bits consumed
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Load the next digit or byte, `false` at the end of the stream
+    fn fill(&mut self) -> Result<bool, DecodeError> {
+        loop {
+            let byte = match self.bytes.next() {
+                None => return Ok(false),
+                Some(Ok(byte)) => byte,
+                Some(Err(e)) => {
+                    return Err(DecodeError::Io {
+                        offset: self.offset,
+                        kind: e.kind(),
+                    })
+                }
+            };
+            match self.encoding {
+                Encoding::Hex if byte.is_ascii_whitespace() => continue,
+                Encoding::Hex => {
+                    let digit = (byte as char).to_digit(16).ok_or(DecodeError::InvalidHex {
+                        offset: self.offset,
+                        found: if byte.is_ascii() {
+                            byte as char
+                        } else {
+                            char::REPLACEMENT_CHARACTER
+                        },
+                    })?;
+                    self.buffer = digit as u8;
+                    self.pending = 4;
+                }
+                Encoding::Raw => {
+                    self.buffer = byte;
+                    self.pending = 8;
+                }
+            }
+            return Ok(true);
+        }
+    }
+
+    /// The next bit, running out of them being reported at `start`
+    fn bit(&mut self, start: usize) -> Result<bool, DecodeError> {
+        if self.pending == 0 && !self.fill()? {
+            return Err(DecodeError::UnexpectedEnd { offset: start });
+        }
+        self.pending -= 1;
+        self.offset += 1;
+        Ok((self.buffer >> self.pending) & 1 == 1)
+    }
+
+    /// The next `count` bits, the most significant first. Panics if `count` exceeds 128.
+    pub fn read(&mut self, count: usize) -> Result<u128, DecodeError> {
+        assert!(count <= 128, "Can't read {} bits at once", count);
+        let start = self.offset;
+        let mut value = 0;
+        for _ in 0..count {
+            value = (value << 1) | self.bit(start)? as u128;
+        }
+        Ok(value)
+    }
+
+    /// Decode the packet starting at the current offset
+    pub fn packet(&mut self) -> Result<Packet, DecodeError> {
+        let offset = self.offset;
+        let header = PacketHeader {
+            version: self.read(3)? as u8,
+            id: self.read(3)? as u8,
+        };
+
+        let value = if header.id == 4 {
+            let mut literal: u128 = 0;
+            loop {
+                let end = self.read(1)? == 0;
+                let group = self.read(4)?;
+                if literal.leading_zeros() < 4 {
+                    return Err(DecodeError::LiteralOverflow { offset });
+                }
+                literal = (literal << 4) | group;
+                if end {
+                    break;
+                }
+            }
+            PacketValue::Literal(literal)
+        } else {
+            let len = if self.read(1)? == 0 {
+                Len::Bits(self.read(15)? as usize)
+            } else {
+                Len::SubPackets(self.read(11)? as usize)
+            };
+            let start = self.offset;
+            let mut subpackets = Vec::new();
+            loop {
+                let done = match len {
+                    Len::Bits(size) => self.offset >= start + size,
+                    Len::SubPackets(size) => subpackets.len() == size,
+                };
+                if done {
+                    break;
+                }
+                subpackets.push(self.packet()?);
+            }
+            if let Len::Bits(size) = len {
+                if self.offset != start + size {
+                    return Err(DecodeError::BadLength {
+                        offset,
+                        expected: size,
+                        actual: self.offset - start,
+                    });
+                }
+            }
+            check_operands(header.id, subpackets.len(), Some(offset))?;
+            PacketValue::Operator(subpackets)
+        };
+
+        Ok(Packet { header, value })
+    }
+
+    /// Skip the padding up to the next whole byte, `false` at the end of the stream
+    fn next_byte(&mut self) -> Result<bool, DecodeError> {
+        while !self.offset.is_multiple_of(8) {
+            if self.pending == 0 && !self.fill()? {
+                return Ok(false);
+            }
+            self.bit(self.offset)?;
+        }
+        Ok(self.pending > 0 || self.fill()?)
+    }
+
+    /// The top-level packets up to the end of the stream, each padded to whole bytes
+    /// the way [`Packet::encode`] does. Stops after the first error.
+    pub fn packets(self) -> Packets<R> {
+        Packets {
+            reader: self,
+            failed: false,
+        }
+    }
+}
+
+pub struct Packets<R> {
+    reader: BitReader<R>,
+    failed: bool,
+}
+
+impl<R: Read> Iterator for Packets<R> {
+    type Item = Result<Packet, DecodeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let packet = match self.reader.next_byte() {
+            Ok(false) => return None,
+            Ok(true) => self.reader.packet(),
+            Err(e) => Err(e),
+        };
+        self.failed = packet.is_err();
+        Some(packet)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::encode::LengthType;
+
+    /// The bytes repeated `times` times, generated as they're read
+    struct Repeat {
+        bytes: Vec<u8>,
+        pos: usize,
+        len: usize,
+    }
+
+    impl Repeat {
+        fn new(bytes: &[u8], times: usize) -> Self {
+            Self {
+                bytes: bytes.to_vec(),
+                pos: 0,
+                len: bytes.len() * times,
+            }
+        }
+    }
+
+    impl Read for Repeat {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let count = buf.len().min(self.len - self.pos);
+            for byte in &mut buf[..count] {
+                *byte = self.bytes[self.pos % self.bytes.len()];
+                self.pos += 1;
+            }
+            Ok(count)
+        }
+    }
+
+    struct Failing;
+
+    impl Read for Failing {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::ErrorKind::ConnectionReset.into())
+        }
+    }
+
+    #[test]
+    fn test_read() {
+        let mut reader = BitReader::new("D2 FE\n28".as_bytes(), Encoding::Hex);
+        assert_eq!(Ok(0b110100), reader.read(6));
+        assert_eq!(Ok(0b1_0111_1111_0001_0100), reader.read(17));
+        assert_eq!(23, reader.offset());
+        assert_eq!(
+            Err(DecodeError::UnexpectedEnd { offset: 23 }),
+            reader.read(2)
+        );
+
+        let mut reader = BitReader::new(&[0xD2, 0xFE, 0x28][..], Encoding::Raw);
+        assert_eq!(Ok(0xD2FE28), reader.read(24));
+
+        let mut reader = BitReader::new(Failing, Encoding::Raw);
+        assert_eq!(
+            Err(DecodeError::Io {
+                offset: 0,
+                kind: io::ErrorKind::ConnectionReset
+            }),
+            reader.read(1)
+        );
+    }
+
+    #[test]
+    fn test_packets() {
+        let hex = [
+            Packet::literal(6, 2021).encode(LengthType::Bits),
+            Packet::literal(0, u128::MAX).encode(LengthType::Bits),
+            "38006F45291200".to_string(),
+        ]
+        .join("\n");
+        let packets: Vec<_> = BitReader::new(hex.as_bytes(), Encoding::Hex)
+            .packets()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(
+            vec![
+                Packet::literal(6, 2021),
+                Packet::literal(0, u128::MAX),
+                Packet::operator(1, 6, vec![Packet::literal(6, 10), Packet::literal(2, 20)]),
+            ],
+            packets
+        );
+
+        // The second packet is cut short, nothing is read after the error
+        let mut packets = BitReader::new("D2FE28D2FE".as_bytes(), Encoding::Hex).packets();
+        assert_eq!(Some(Ok(Packet::literal(6, 2021))), packets.next());
+        assert_eq!(
+            Some(Err(DecodeError::UnexpectedEnd { offset: 40 })),
+            packets.next()
+        );
+        assert_eq!(None, packets.next());
+    }
+
+    #[test]
+    fn test_large_transmission() {
+        let times = 100_000;
+        let reader = Repeat::new(&[0xD2, 0xFE, 0x28], times);
+        let (count, sum) = BitReader::new(reader, Encoding::Raw)
+            .packets()
+            .fold((0, 0), |(count, sum), packet| {
+                (count + 1, sum + packet.unwrap().eval().unwrap())
+            });
+        assert_eq!((times, 2021 * times as u128), (count, sum));
+
+        let reader = Repeat::new(b"D2FE28\n", times);
+        assert_eq!(
+            times,
+            BitReader::new(reader, Encoding::Hex).packets().count()
+        );
+    }
+}
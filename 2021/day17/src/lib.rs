@@ -0,0 +1,115 @@
+use std::cmp::Ordering;
+
+use utils::solution::Solution;
+
+extern crate utils;
+type Vec2 = utils::Vec2<isize>;
+
+struct Probe {
+    pos: Vec2,
+    vel: Vec2,
+}
+
+impl Probe {
+    fn new(v: Vec2) -> Self {
+        Self {
+            pos: Vec2::default(),
+            vel: v,
+        }
+    }
+
+    fn step(&mut self) {
+        self.pos += self.vel;
+        match self.vel.x.cmp(&0) {
+            Ordering::Greater => {
+                self.vel.x -= 1;
+            }
+            Ordering::Less => {
+                self.vel.x -= 1;
+            }
+            _ => {}
+        }
+
+        self.vel.y -= 1;
+    }
+}
+
+fn missed(probe: &Probe, point: Vec2) -> bool {
+    probe.pos.x > point.x || probe.pos.y < point.y
+}
+
+fn in_area(probe: &Probe, (point_lu, point_rd): (Vec2, Vec2)) -> bool {
+    (point_lu.x..=point_rd.x).contains(&probe.pos.x)
+        && (point_rd.y..=point_lu.y).contains(&probe.pos.y)
+}
+
+/// Parse the target area into its left-upper and right-down corners
+fn parse_target_area(line: &str) -> (Vec2, Vec2) {
+    let (x, y) = line
+        .trim()
+        .strip_prefix("target area: x=")
+        .and_then(|s| s.split_once(", y="))
+        .expect("invalid target area");
+    let range = |s: &str| -> (isize, isize) {
+        let (from, to) = s.split_once("..").expect("invalid range");
+        (from.parse().unwrap(), to.parse().unwrap())
+    };
+    let (x_from, x_to) = range(x);
+    let (y_from, y_to) = range(y);
+    (Vec2 { x: x_from, y: y_to }, Vec2 { x: x_to, y: y_from })
+}
+
+/// Try all initial velocities that can possibly hit the area.
+/// Returns the number of hitting velocities and the highest
+/// Y velocity among them.
+fn launch_probes((point_lu, point_rd): (Vec2, Vec2)) -> (usize, Option<isize>) {
+    let mut cnt = 0;
+    let mut highest_vy = None;
+    for y in point_rd.y..-point_rd.y {
+        for x in 1..=point_rd.x {
+            let mut probe = Probe::new(Vec2 { x, y });
+            while !missed(&probe, point_rd) {
+                if in_area(&probe, (point_lu, point_rd)) {
+                    cnt += 1;
+                    highest_vy = Some(y);
+                    break;
+                }
+                probe.step();
+            }
+        }
+    }
+    (cnt, highest_vy)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 17;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input<'a> = (Vec2, Vec2);
+    type Answer1 = isize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_target_area(input)
+    }
+
+    fn part1(area: &Self::Input<'_>) -> Option<isize> {
+        let (_, highest_vy) = launch_probes(*area);
+        let mut probe = Probe::new(Vec2 {
+            x: 0,
+            y: highest_vy?,
+        });
+        while probe.vel.y >= 0 {
+            probe.step();
+        }
+        Some(probe.pos.y)
+    }
+
+    fn part2(area: &Self::Input<'_>) -> Option<usize> {
+        let (cnt, _) = launch_probes(*area);
+        Some(cnt)
+    }
+}
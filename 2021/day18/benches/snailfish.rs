@@ -0,0 +1,51 @@
+//! The boxed tree against the flat list of values, on the real input.
+
+use std::cmp::max;
+use std::iter::Sum;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day18_2021::{flat::FlatNumber, BinaryTree, Day18};
+use utils::solution::Solution;
+
+/// The largest magnitude of the sum of two different numbers, like part 2
+fn largest_sum<T>(numbers: &[T], magnitude: impl Fn(&T) -> i32) -> Option<i32>
+where
+    T: for<'a> Sum<&'a T>,
+{
+    let mut largest = None;
+    for (i, a) in numbers.iter().enumerate() {
+        for (j, b) in numbers.iter().enumerate() {
+            if i != j {
+                largest = max(largest, Some(magnitude(&[a, b].into_iter().sum())));
+            }
+        }
+    }
+    largest
+}
+
+fn bench_snailfish(c: &mut Criterion) {
+    let trees: Vec<BinaryTree> = Day18::INPUT
+        .lines()
+        .map(|line| line.parse().unwrap())
+        .collect();
+    let flats: Vec<FlatNumber> = trees.iter().map(FlatNumber::from).collect();
+
+    let mut group = c.benchmark_group("2021-day18-snailfish");
+    group.sample_size(10);
+    group.bench_function("tree/sum", |b| {
+        b.iter(|| black_box(&trees).iter().sum::<BinaryTree>().magnitude())
+    });
+    group.bench_function("flat/sum", |b| {
+        b.iter(|| black_box(&flats).iter().sum::<FlatNumber>().magnitude())
+    });
+    group.bench_function("tree/pairs", |b| {
+        b.iter(|| largest_sum(black_box(&trees), BinaryTree::magnitude))
+    });
+    group.bench_function("flat/pairs", |b| {
+        b.iter(|| largest_sum(black_box(&flats), FlatNumber::magnitude))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_snailfish);
+criterion_main!(benches);
@@ -0,0 +1,188 @@
+//! Snailfish numbers as the list of their regular numbers, each with the number
+//! of pairs it's nested in. Reducing them only moves values around in a `Vec`.
+
+use std::{fmt::Display, iter::Sum, ops::Add, str::FromStr};
+
+use crate::{BinaryTree, ParseError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlatNumber {
+    /// `(depth, value)` of the regular numbers from left to right
+    values: Vec<(u8, i32)>,
+}
+
+impl From<&BinaryTree> for FlatNumber {
+    fn from(tree: &BinaryTree) -> Self {
+        fn flatten(tree: &BinaryTree, depth: u8, values: &mut Vec<(u8, i32)>) {
+            match tree {
+                BinaryTree::Literal(value) => values.push((depth, *value)),
+                BinaryTree::Number(left, right) => {
+                    flatten(left, depth + 1, values);
+                    flatten(right, depth + 1, values);
+                }
+            }
+        }
+        let mut values = Vec::new();
+        flatten(tree, 0, &mut values);
+        Self { values }
+    }
+}
+
+impl FromStr for FlatNumber {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<BinaryTree>().map(|tree| Self::from(&tree))
+    }
+}
+
+/// The puzzle notation, like `[[1,2],3]`
+impl Display for FlatNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fn write(
+            f: &mut std::fmt::Formatter<'_>,
+            values: &mut std::slice::Iter<(u8, i32)>,
+            depth: u8,
+        ) -> std::fmt::Result {
+            match values.as_slice().first() {
+                Some(&(value_depth, value)) if value_depth == depth => {
+                    values.next();
+                    write!(f, "{}", value)
+                }
+                _ => {
+                    write!(f, "[")?;
+                    write(f, values, depth + 1)?;
+                    write!(f, ",")?;
+                    write(f, values, depth + 1)?;
+                    write!(f, "]")
+                }
+            }
+        }
+        write(f, &mut self.values.iter(), 0)
+    }
+}
+
+/// The pair of both numbers, without reducing it
+impl Add for FlatNumber {
+    type Output = FlatNumber;
+
+    fn add(mut self, rhs: FlatNumber) -> Self::Output {
+        self.values.extend(rhs.values);
+        for (depth, _) in &mut self.values {
+            *depth += 1;
+        }
+        self
+    }
+}
+
+/// Snailfish addition of the numbers in order, reducing each sum.
+/// Panics if there are no numbers.
+impl Sum for FlatNumber {
+    fn sum<I: Iterator<Item = FlatNumber>>(mut iter: I) -> Self {
+        let first = iter.next().expect("No snailfish numbers to add");
+        iter.fold(first, |acc, number| {
+            let mut sum = acc + number;
+            sum.reduce();
+            sum
+        })
+    }
+}
+
+impl<'a> Sum<&'a FlatNumber> for FlatNumber {
+    fn sum<I: Iterator<Item = &'a FlatNumber>>(iter: I) -> Self {
+        iter.cloned().sum()
+    }
+}
+
+impl FlatNumber {
+    /// Explode the leftmost pair of regular numbers nested in four pairs,
+    /// `false` if there's none
+    fn explode(&mut self) -> bool {
+        // Two values next to each other at the same depth, the first of them
+        // is a pair of regular numbers
+        let Some(idx) = self
+            .values
+            .windows(2)
+            .position(|pair| pair[0].0 > 4 && pair[0].0 == pair[1].0)
+        else {
+            return false;
+        };
+        let (depth, left) = self.values[idx];
+        let (_, right) = self.values.remove(idx + 1);
+        if let Some((_, value)) = idx.checked_sub(1).map(|prev| &mut self.values[prev]) {
+            *value += left;
+        }
+        if let Some((_, value)) = self.values.get_mut(idx + 1) {
+            *value += right;
+        }
+        self.values[idx] = (depth - 1, 0);
+        true
+    }
+
+    /// Split the leftmost regular number greater than 9, `false` if there's none
+    fn split(&mut self) -> bool {
+        let Some(idx) = self.values.iter().position(|(_, value)| *value > 9) else {
+            return false;
+        };
+        let (depth, value) = self.values[idx];
+        self.values[idx] = (depth + 1, value / 2);
+        self.values.insert(idx + 1, (depth + 1, value - value / 2));
+        true
+    }
+
+    pub fn reduce(&mut self) {
+        while self.explode() || self.split() {}
+    }
+
+    pub fn magnitude(&self) -> i32 {
+        // Merge the pairs as soon as both their values are on the stack
+        let mut stack: Vec<(u8, i32)> = Vec::new();
+        for &(mut depth, mut value) in &self.values {
+            while let Some(&(top_depth, left)) = stack.last() {
+                if top_depth != depth {
+                    break;
+                }
+                stack.pop();
+                value = 3 * left + 2 * value;
+                depth -= 1;
+            }
+            stack.push((depth, value));
+        }
+        assert_eq!(1, stack.len(), "Not a snailfish number");
+        stack[0].1
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn number(s: &str) -> FlatNumber {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_display() {
+        for text in ["7", "[1,2]", "[[1,[2,3]],[[4,5],6]]"] {
+            assert_eq!(text, number(text).to_string());
+        }
+    }
+
+    #[test]
+    fn test_reduce() {
+        let mut sum = number("[[[[4,3],4],4],[7,[[8,4],9]]]") + number("[1,1]");
+        assert_eq!("[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]", sum.to_string());
+        sum.reduce();
+        assert_eq!("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]", sum.to_string());
+    }
+
+    #[test]
+    fn test_magnitude() {
+        assert_eq!(29, number("[9,1]").magnitude());
+        assert_eq!(129, number("[[9,1],[1,9]]").magnitude());
+        assert_eq!(
+            3488,
+            number("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]").magnitude()
+        );
+    }
+}
@@ -0,0 +1,659 @@
+use std::{
+    cmp::{max, Ordering},
+    fmt::{Debug, Display},
+    iter::Sum,
+    num::ParseIntError,
+    str::FromStr,
+};
+
+use flat::FlatNumber;
+use nom::{
+    branch::alt,
+    character::complete::{char, digit1},
+    combinator::{map, map_res},
+    error::FromExternalError,
+    sequence::tuple,
+    IResult, Parser,
+};
+use utils::solution::Solution;
+
+pub mod flat;
+
+#[derive(PartialEq, Clone)]
+pub enum BinaryTree {
+    Literal(i32),
+    Number(Box<BinaryTree>, Box<BinaryTree>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    Expected(char),
+    /// Neither a number nor a pair
+    ExpectedValue,
+    /// Doesn't fit in an `i32`
+    InvalidNumber,
+    Trailing,
+}
+
+/// Where and why the parsing failed. The column is 1-based.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseError {
+    pub column: usize,
+    pub kind: ErrorKind,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "column {}: ", self.column)?;
+        match self.kind {
+            ErrorKind::Expected(c) => write!(f, "expected `{}`", c),
+            ErrorKind::ExpectedValue => write!(f, "expected a number or a pair"),
+            ErrorKind::InvalidNumber => write!(f, "the number is too large"),
+            ErrorKind::Trailing => write!(f, "unexpected input after the number"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The nom error, with the input left where it failed
+#[derive(Debug)]
+struct Failure<'a> {
+    input: &'a str,
+    kind: ErrorKind,
+}
+
+impl<'a> nom::error::ParseError<&'a str> for Failure<'a> {
+    fn from_error_kind(input: &'a str, _: nom::error::ErrorKind) -> Self {
+        Self {
+            input,
+            kind: ErrorKind::ExpectedValue,
+        }
+    }
+
+    fn append(_: &'a str, _: nom::error::ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    fn from_char(input: &'a str, c: char) -> Self {
+        Self {
+            input,
+            kind: ErrorKind::Expected(c),
+        }
+    }
+
+    /// Keep the alternative that got further
+    fn or(self, other: Self) -> Self {
+        match self.input.len().cmp(&other.input.len()) {
+            Ordering::Less => self,
+            Ordering::Greater => other,
+            Ordering::Equal => match (self.kind, other.kind) {
+                (ErrorKind::InvalidNumber, _) => self,
+                (_, ErrorKind::InvalidNumber) => other,
+                _ => Self {
+                    kind: ErrorKind::ExpectedValue,
+                    ..self
+                },
+            },
+        }
+    }
+}
+
+impl<'a> FromExternalError<&'a str, ParseIntError> for Failure<'a> {
+    fn from_external_error(input: &'a str, _: nom::error::ErrorKind, _: ParseIntError) -> Self {
+        Self {
+            input,
+            kind: ErrorKind::InvalidNumber,
+        }
+    }
+}
+
+impl FromStr for BinaryTree {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = |rest: &str, kind| ParseError {
+            column: s[..s.len() - rest.len()].chars().count() + 1,
+            kind,
+        };
+        match parse_value_tree(s) {
+            Ok(("", tree)) => Ok(tree),
+            Ok((rest, _)) => Err(error(rest, ErrorKind::Trailing)),
+            Err(nom::Err::Error(failure) | nom::Err::Failure(failure)) => {
+                Err(error(failure.input, failure.kind))
+            }
+            Err(nom::Err::Incomplete(_)) => unreachable!("The parsers are complete"),
+        }
+    }
+}
+
+/// The puzzle notation, like `[[1,2],3]`
+impl Display for BinaryTree {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Literal(val) => write!(f, "{}", val),
+            Self::Number(left, right) => write!(f, "[{},{}]", left, right),
+        }
+    }
+}
+
+impl Debug for BinaryTree {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Literal(val) => {
+                write!(f, "{}", *val)?;
+            }
+            Self::Number(left, right) => {
+                write!(f, "[{:?}, {:?}]", left, right)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::ops::Add<BinaryTree> for BinaryTree {
+    type Output = BinaryTree;
+
+    fn add(self, rhs: BinaryTree) -> Self::Output {
+        match (&self, &rhs) {
+            (BinaryTree::Literal(left), BinaryTree::Literal(right)) => {
+                BinaryTree::Literal(left + right)
+            }
+            _ => BinaryTree::Number(Box::new(self), Box::new(rhs)),
+        }
+    }
+}
+
+/// Snailfish addition of the numbers in order, reducing each sum.
+/// Panics if there are no numbers.
+impl Sum for BinaryTree {
+    fn sum<I: Iterator<Item = BinaryTree>>(mut iter: I) -> Self {
+        let first = iter.next().expect("No snailfish numbers to add");
+        iter.fold(first, |acc, tree| {
+            let mut sum = acc + tree;
+            sum.reduce();
+            sum
+        })
+    }
+}
+
+impl<'a> Sum<&'a BinaryTree> for BinaryTree {
+    fn sum<I: Iterator<Item = &'a BinaryTree>>(iter: I) -> Self {
+        iter.cloned().sum()
+    }
+}
+
+/// A single step of the reduction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// The pair that exploded
+    Explode(i32, i32),
+    /// The number that split
+    Split(i32),
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Action::Explode(left, right) => write!(f, "explode [{},{}]", left, right),
+            Action::Split(value) => write!(f, "split {}", value),
+        }
+    }
+}
+
+/// Iterator over the actions reducing a number, with the number after each
+pub struct Reduction {
+    tree: BinaryTree,
+}
+
+impl Iterator for Reduction {
+    type Item = (Action, BinaryTree);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let action = self.tree.reduce_step()?;
+        Some((action, self.tree.clone()))
+    }
+}
+
+impl BinaryTree {
+    pub fn magnitude(&self) -> i32 {
+        match self {
+            BinaryTree::Literal(value) => *value,
+            BinaryTree::Number(left, right) => 3 * left.magnitude() + 2 * right.magnitude(),
+        }
+    }
+
+    /// The number of nested pairs, 0 for a regular number
+    pub fn depth(&self) -> usize {
+        match self {
+            BinaryTree::Literal(_) => 0,
+            BinaryTree::Number(left, right) => max(left.depth(), right.depth()) + 1,
+        }
+    }
+
+    pub fn max_value(&self) -> i32 {
+        match self {
+            BinaryTree::Literal(value) => *value,
+            BinaryTree::Number(left, right) => max(left.max_value(), right.max_value()),
+        }
+    }
+
+    /// The leftmost pair of regular numbers nested in four pairs
+    fn exploding_pair(&self, nesting: usize) -> Option<(i32, i32)> {
+        match self {
+            BinaryTree::Literal(_) => None,
+            BinaryTree::Number(left, right) => match (&**left, &**right) {
+                (BinaryTree::Literal(left), BinaryTree::Literal(right)) if nesting >= 4 => {
+                    Some((*left, *right))
+                }
+                _ => left
+                    .exploding_pair(nesting + 1)
+                    .or_else(|| right.exploding_pair(nesting + 1)),
+            },
+        }
+    }
+
+    /// The leftmost regular number that needs to split
+    fn splitting_value(&self) -> Option<i32> {
+        match self {
+            BinaryTree::Literal(value) => (*value > 9).then_some(*value),
+            BinaryTree::Number(left, right) => {
+                left.splitting_value().or_else(|| right.splitting_value())
+            }
+        }
+    }
+
+    /// Apply the first action reducing the number, `None` if it's already reduced
+    pub fn reduce_step(&mut self) -> Option<Action> {
+        if self.depth() >= 5 {
+            let (left, right) = self.exploding_pair(0)?;
+            self.explode();
+            Some(Action::Explode(left, right))
+        } else if self.max_value() > 9 {
+            let value = self.splitting_value()?;
+            self.split();
+            Some(Action::Split(value))
+        } else {
+            None
+        }
+    }
+
+    pub fn reduce(&mut self) {
+        while self.reduce_step().is_some() {}
+    }
+
+    /// The steps of [`BinaryTree::reduce`], to follow them one by one
+    pub fn reduction(self) -> Reduction {
+        Reduction { tree: self }
+    }
+
+    fn add_to_leftmost(&mut self, tree: BinaryTree) {
+        match self {
+            BinaryTree::Literal(_) => *self = self.clone() + tree,
+            BinaryTree::Number(left, _) => left.add_to_leftmost(tree),
+        }
+    }
+
+    fn add_to_rightmost(&mut self, tree: BinaryTree) {
+        match self {
+            BinaryTree::Literal(_) => *self = self.clone() + tree,
+            BinaryTree::Number(_, right) => right.add_to_rightmost(tree),
+        }
+    }
+
+    /// Explode the leftmost pair of regular numbers nested in four pairs
+    pub fn explode(&mut self) {
+        self.maybe_explode(0);
+    }
+
+    /// Explode the leftmost pair of regular numbers nested in four pairs, counting
+    /// the ones around this one. `None` if there's none, otherwise the values still
+    /// to be added to the regular numbers left and right of this one.
+    fn maybe_explode(
+        &mut self,
+        nesting: usize,
+    ) -> Option<(Option<BinaryTree>, Option<BinaryTree>)> {
+        let BinaryTree::Number(left, right) = self else {
+            return None;
+        };
+        if let (BinaryTree::Literal(_), BinaryTree::Literal(_)) = (&**left, &**right) {
+            if nesting < 4 {
+                return None;
+            }
+            let exploded = (Some(*left.clone()), Some(*right.clone()));
+            *self = BinaryTree::Literal(0);
+            return Some(exploded);
+        }
+        if let Some((exp_left, exp_right)) = left.maybe_explode(nesting + 1) {
+            if let Some(exp_right) = exp_right {
+                right.add_to_leftmost(exp_right);
+            }
+            // Pass left up
+            return Some((exp_left, None));
+        }
+        let (exp_left, exp_right) = right.maybe_explode(nesting + 1)?;
+        if let Some(exp_left) = exp_left {
+            left.add_to_rightmost(exp_left);
+        }
+        // Pass right up
+        Some((None, exp_right))
+    }
+
+    /// Split the leftmost regular number greater than 9, `false` if there's none
+    pub fn split(&mut self) -> bool {
+        match self {
+            BinaryTree::Literal(value) => {
+                if *value > 9 {
+                    let half = *value as f64 / 2.;
+                    *self = BinaryTree::Number(
+                        Box::new(BinaryTree::Literal(half.floor() as i32)),
+                        Box::new(BinaryTree::Literal(half.ceil() as i32)),
+                    );
+                    true
+                } else {
+                    false
+                }
+            }
+            BinaryTree::Number(left, right) => left.split() || right.split(),
+        }
+    }
+}
+
+fn parse_value_tree(input: &str) -> IResult<&str, BinaryTree, Failure<'_>> {
+    alt((
+        map(map_res(digit1, str::parse), BinaryTree::Literal),
+        parse_number_tree,
+    ))
+    .parse(input)
+}
+
+fn parse_number_tree(input: &str) -> IResult<&str, BinaryTree, Failure<'_>> {
+    let (input, (_, val0, _, val1, _)) = tuple((
+        char('['),
+        parse_value_tree,
+        char(','),
+        parse_value_tree,
+        char(']'),
+    ))(input)?;
+
+    Ok((input, BinaryTree::Number(Box::new(val0), Box::new(val1))))
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 18;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input<'a> = Vec<FlatNumber>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(|line| {
+                line.parse()
+                    .unwrap_or_else(|e| panic!("Invalid number {}: {}", line, e))
+            })
+            .collect()
+    }
+
+    fn part1(numbers: &Self::Input<'_>) -> Option<i32> {
+        if numbers.is_empty() {
+            return None;
+        }
+        Some(numbers.iter().sum::<FlatNumber>().magnitude())
+    }
+
+    fn part2(numbers: &Self::Input<'_>) -> Option<i32> {
+        let mut magnitude = None;
+        for (i, a) in numbers.iter().enumerate() {
+            for (j, b) in numbers.iter().enumerate() {
+                if i == j {
+                    continue;
+                }
+                let sum: FlatNumber = [a, b].into_iter().sum();
+                magnitude = max(magnitude, Some(sum.magnitude()));
+            }
+        }
+        magnitude
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn number(s: &str) -> BinaryTree {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            number("[1,2]"),
+            BinaryTree::Number(
+                Box::new(BinaryTree::Literal(1)),
+                Box::new(BinaryTree::Literal(2))
+            )
+        );
+
+        assert_eq!(
+            number("[1,[2,3]]"),
+            BinaryTree::Number(
+                Box::new(BinaryTree::Literal(1)),
+                Box::new(BinaryTree::Number(
+                    Box::new(BinaryTree::Literal(2)),
+                    Box::new(BinaryTree::Literal(3))
+                ))
+            )
+        );
+    }
+
+    #[test]
+    fn test_btree_depth() {
+        assert_eq!(1, number("[1,2]").depth());
+        assert_eq!(2, number("[1,[2,3]]").depth());
+        assert_eq!(5, number("[[[[[9,8],1],2],3],4]").depth());
+    }
+
+    #[test]
+    fn test_btree_max() {
+        assert_eq!(2, number("[1,2]").max_value());
+        assert_eq!(3, number("[1,[2,3]]").max_value());
+        assert_eq!(9, number("[[[[[9,8],1],2],3],4]").max_value());
+    }
+
+    #[test]
+    fn test_btree_add() {
+        assert_eq!(number("3"), number("1") + number("2"));
+        assert_eq!(number("[[1,2],3]"), number("[1,2]") + number("3"));
+
+        assert_eq!(number("[[1,2],[3,4]]"), number("[1,2]") + number("[3,4]"));
+        assert_eq!(
+            number("[[1,2],[[3,4],5]]"),
+            number("[1,2]") + number("[[3,4],5]")
+        );
+        assert_eq!(
+            number("[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]"),
+            number("[[[[4,3],4],4],[7,[[8,4],9]]]") + number("[1,1]")
+        );
+    }
+
+    #[test]
+    fn test_btree_split() {
+        let mut tree = number("10");
+        tree.split();
+        assert_eq!(number("[5,5]"), tree);
+
+        let mut tree = number("11");
+        tree.split();
+        assert_eq!(number("[5,6]"), tree);
+
+        let mut tree = number("[1,10]");
+        tree.split();
+        assert_eq!(number("[1,[5,5]]"), tree);
+    }
+
+    #[test]
+    fn test_btree_explode() {
+        let mut tree = number("1");
+        tree.explode();
+        assert_eq!(number("1"), tree);
+
+        let mut tree = number("[1,2]");
+        tree.explode();
+        assert_eq!(number("[1,2]"), tree);
+
+        let mut tree = number("[[[[[9,8],1],2],3],4]");
+        tree.explode();
+        assert_eq!(number("[[[[0,9],2],3],4]"), tree);
+
+        let mut tree = number("[7,[6,[5,[4,[3,2]]]]]");
+        tree.explode();
+        assert_eq!(number("[7,[6,[5,[7,0]]]]"), tree);
+
+        let mut tree = number("[[6,[5,[4,[3,2]]]],1]");
+        tree.explode();
+        assert_eq!(number("[[6,[5,[7,0]]],3]"), tree);
+
+        let mut tree = number("[[3,[2,[1,[7,3]]]],[6,[5,[4,[3,2]]]]]");
+        tree.explode();
+        assert_eq!(number("[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]"), tree);
+
+        let mut tree = number("[[3,[2,[8,0]]],[9,[5,[4,[3,2]]]]]");
+        tree.explode();
+        assert_eq!(number("[[3,[2,[8,0]]],[9,[5,[7,0]]]]"), tree);
+    }
+
+    #[test]
+    fn test_reduce() {
+        let mut tree = number("[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]");
+        tree.reduce();
+        assert_eq!(number("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]"), tree);
+    }
+
+    #[test]
+    fn test_part1() {
+        let input = [
+            "[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]",
+            "[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]",
+            "[[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]",
+            "[[[[2,4],7],[6,[0,5]]],[[[6,8],[2,8]],[[2,1],[4,5]]]]",
+            "[7,[5,[[3,8],[1,4]]]]",
+            "[[2,[2,2]],[8,[8,1]]]",
+            "[2,9]",
+            "[1,[[[9,3],9],[[9,0],[0,7]]]]",
+            "[[[5,[7,4]],7],1]",
+            "[[[[4,2],2],6],[8,7]]",
+        ];
+
+        let sum: BinaryTree = input.into_iter().map(number).sum();
+
+        assert_eq!(
+            "[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]",
+            sum.to_string()
+        );
+        assert_eq!(3488, sum.magnitude());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |column, kind| Err(ParseError { column, kind });
+        assert_eq!(error(1, ErrorKind::ExpectedValue), "".parse::<BinaryTree>());
+        assert_eq!(
+            error(3, ErrorKind::Expected(',')),
+            "[1;2]".parse::<BinaryTree>()
+        );
+        assert_eq!(
+            error(5, ErrorKind::Expected(']')),
+            "[1,2,3]".parse::<BinaryTree>()
+        );
+        assert_eq!(
+            error(4, ErrorKind::ExpectedValue),
+            "[1,-2]".parse::<BinaryTree>()
+        );
+        assert_eq!(
+            error(2, ErrorKind::InvalidNumber),
+            "[99999999999,1]".parse::<BinaryTree>()
+        );
+        assert_eq!(
+            error(6, ErrorKind::Trailing),
+            "[1,2]]".parse::<BinaryTree>()
+        );
+        assert_eq!(
+            "column 4: expected a number or a pair",
+            "[1,]".parse::<BinaryTree>().unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let text = "[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]";
+        assert_eq!(text, number(text).to_string());
+        assert_eq!("[[1, 2], 3]", format!("{:?}", number("[[1,2],3]")));
+    }
+
+    #[test]
+    fn test_reduction() {
+        let steps: Vec<_> = (number("[[[[4,3],4],4],[7,[[8,4],9]]]") + number("[1,1]"))
+            .reduction()
+            .map(|(action, tree)| format!("{}: {}", action, tree))
+            .collect();
+        assert_eq!(
+            vec![
+                "explode [4,3]: [[[[0,7],4],[7,[[8,4],9]]],[1,1]]",
+                "explode [8,4]: [[[[0,7],4],[15,[0,13]]],[1,1]]",
+                "split 15: [[[[0,7],4],[[7,8],[0,13]]],[1,1]]",
+                "split 13: [[[[0,7],4],[[7,8],[0,[6,7]]]],[1,1]]",
+                "explode [6,7]: [[[[0,7],4],[[7,8],[6,0]]],[8,1]]",
+            ],
+            steps
+        );
+    }
+
+    #[test]
+    fn test_sum() {
+        let sum: BinaryTree = ["[1,1]", "[2,2]", "[3,3]", "[4,4]", "[5,5]", "[6,6]"]
+            .into_iter()
+            .map(number)
+            .sum();
+        assert_eq!("[[[[5,0],[7,4]],[5,5]],[6,6]]", sum.to_string());
+    }
+
+    #[test]
+    fn test_flat_matches_tree() {
+        // Nested deeper than the sums of reduced numbers ever get
+        for text in [
+            "[[[[[1,[2,3]],4],5],6],7]",
+            "[[[[[[[1,2],3],4],5],6],7],8]",
+            "[1,[2,[3,[4,[5,[6,[7,8]]]]]]]",
+            "[[[[[[1,2],[3,4]],5],6],7],[[[[[8,9],[10,11]],12],13],14]]",
+        ] {
+            let mut tree = number(text);
+            let mut flat: FlatNumber = text.parse().unwrap();
+            tree.reduce();
+            flat.reduce();
+            assert_eq!(tree.to_string(), flat.to_string(), "{}", text);
+            assert_eq!(tree.magnitude(), flat.magnitude(), "{}", text);
+        }
+
+        let trees: Vec<BinaryTree> = Day18::INPUT.lines().map(number).collect();
+        let flats = Day18::parse(Day18::INPUT);
+        assert_eq!(
+            trees.iter().sum::<BinaryTree>().magnitude(),
+            flats.iter().sum::<FlatNumber>().magnitude()
+        );
+        for (i, (tree_a, flat_a)) in trees.iter().zip(&flats).enumerate() {
+            for (j, (tree_b, flat_b)) in trees.iter().zip(&flats).enumerate() {
+                if i != j {
+                    let tree: BinaryTree = [tree_a, tree_b].into_iter().sum();
+                    let flat: FlatNumber = [flat_a, flat_b].into_iter().sum();
+                    assert_eq!(tree.magnitude(), flat.magnitude(), "{} + {}", i, j);
+                }
+            }
+        }
+    }
+}
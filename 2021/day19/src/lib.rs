@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use itertools::Itertools;
+use utils::solution::Solution;
+
+type Vec3 = utils::Vec3<i32>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Scanner {
+    probes: Vec<Vec3>,
+}
+
+#[derive(Debug)]
+pub struct LocatedScanner {
+    position: Vec3,
+    scanner: Scanner,
+}
+
+impl Scanner {
+    fn all_rotations(&self) -> impl IntoIterator<Item = Scanner> + '_ {
+        (0..Vec3::ROTATIONS)
+            .map(|rot| Self {
+                probes: self.probes.iter().map(|p| p.rotate(rot)).collect(),
+            })
+            .collect::<Vec<Scanner>>()
+    }
+
+    fn locate_other(&self, other: &Scanner) -> Option<LocatedScanner> {
+        for rotated_other in other.all_rotations() {
+            let mut distances = HashMap::<Vec3, usize>::new();
+            let distanes_iter = self.probes.iter().flat_map(|p| {
+                rotated_other
+                    .probes
+                    .iter()
+                    .map(move |other_p| *other_p - *p)
+            });
+            for distance in distanes_iter {
+                *distances.entry(distance).or_default() += 1;
+            }
+            for (dist, count) in distances {
+                if count >= 12 {
+                    // other is a neighbor
+                    return Some(LocatedScanner {
+                        position: -dist,
+                        scanner: Scanner {
+                            probes: rotated_other.probes.iter().map(|p| *p - dist).collect(),
+                        },
+                    });
+                }
+            }
+        }
+        None
+    }
+}
+
+fn parse_input<'a>(input: impl IntoIterator<Item = &'a str>) -> Vec<Scanner> {
+    let mut input = input.into_iter().peekable();
+    let mut scanners = Vec::<Scanner>::new();
+    while input.peek().is_some() {
+        let scanner = input
+            .by_ref()
+            .skip(1)
+            .take_while(|line| !line.is_empty())
+            .map(|line| {
+                let (x, y, z) = line
+                    .splitn(3, ',')
+                    .map(|val| val.parse().unwrap())
+                    .collect_tuple()
+                    .unwrap();
+                Vec3::new(x, y, z)
+            })
+            .collect();
+
+        scanners.push(Scanner { probes: scanner });
+    }
+    scanners
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 19;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    /// Locating the scanners is the expensive bit both parts share,
+    /// so it's done once while parsing.
+    type Input<'a> = Vec<LocatedScanner>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        locate_all_scanners(parse_input(input.lines()))
+    }
+
+    fn part1(located_scanners: &Self::Input<'_>) -> Option<usize> {
+        Some(find_all_unique_beacons(located_scanners).len())
+    }
+
+    fn part2(located_scanners: &Self::Input<'_>) -> Option<usize> {
+        Some(find_manhattan_distance(located_scanners))
+    }
+}
+
+fn locate_all_scanners(mut scanners: Vec<Scanner>) -> Vec<LocatedScanner> {
+    let mut located_scanners = Vec::<LocatedScanner>::from([LocatedScanner {
+        position: Vec3::default(),
+        scanner: scanners.remove(0),
+    }]);
+
+    while !scanners.is_empty() {
+        let mut new_located_scanners = Vec::new();
+        for located in &located_scanners {
+            scanners.retain(|scanner| {
+                if let Some(located_scanner) = located.scanner.locate_other(scanner) {
+                    new_located_scanners.push(located_scanner);
+                    false
+                } else {
+                    true
+                }
+            });
+        }
+        located_scanners.extend(new_located_scanners);
+    }
+
+    located_scanners
+}
+
+fn find_all_unique_beacons(scanners: &[LocatedScanner]) -> HashSet<Vec3> {
+    HashSet::<Vec3>::from_iter(
+        scanners
+            .iter()
+            .flat_map(|scanner| scanner.scanner.probes.iter().copied()),
+    )
+}
+
+fn find_manhattan_distance(scanners: &[LocatedScanner]) -> usize {
+    scanners
+        .iter()
+        .combinations(2)
+        .map(|pair| pair[0].position.manhattan_distance(pair[1].position) as usize)
+        .max()
+        .unwrap()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_rotations() {
+        let scanner = Scanner {
+            probes: vec![Vec3::new(1, 2, 3)],
+        };
+        assert_eq!(24, scanner.all_rotations().into_iter().count());
+    }
+
+    #[test]
+    fn test_common_beacons() {
+        let scanners = parse_input(include_str!("test_data.txt").lines());
+
+        let located = scanners[0].locate_other(&scanners[1]);
+        assert!(located.is_some());
+        if let Some(located) = located {
+            assert_eq!(Vec3::new(68, -1246, -43), located.position);
+
+            let located_4_to_1 = located.scanner.locate_other(&scanners[4]);
+            assert!(located_4_to_1.is_some());
+            if let Some(located) = located_4_to_1 {
+                assert_eq!(Vec3::new(-20, -1133, 1061), located.position);
+            }
+        }
+    }
+
+    #[test]
+    fn test_part1() {
+        let scanners = parse_input(include_str!("test_data.txt").lines());
+
+        let located_scanners = locate_all_scanners(scanners);
+        assert_eq!(5, located_scanners.len());
+
+        let probes = find_all_unique_beacons(&located_scanners);
+        assert_eq!(79, probes.len());
+
+        let max_dist = find_manhattan_distance(&located_scanners);
+        assert_eq!(3621, max_dist);
+    }
+}
@@ -0,0 +1,196 @@
+extern crate utils;
+
+type Vec2 = utils::Vec2<isize>;
+
+use utils::solution::Solution;
+use utils::SparseGrid;
+
+use std::fmt::Display;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Pixel {
+    #[default]
+    Dark,
+    Lit,
+}
+
+impl Display for Pixel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Lit => write!(f, "#"),
+            Self::Dark => write!(f, "."),
+        }
+    }
+}
+
+impl From<char> for Pixel {
+    fn from(c: char) -> Self {
+        match c {
+            '#' => Self::Lit,
+            '.' => Self::Dark,
+            _ => unreachable!("Only '#' and '.' are present in the input"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Algorithm {
+    map: Vec<Pixel>,
+}
+
+impl Algorithm {
+    fn enchance(&self, idx: usize) -> Pixel {
+        self.map[idx]
+    }
+}
+
+impl From<&str> for Algorithm {
+    fn from(line: &str) -> Self {
+        Self {
+            map: line.chars().map(Pixel::from).collect(),
+        }
+    }
+}
+
+/// An infinite image, with all pixels but a finite number of them being the background
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Image {
+    grid: SparseGrid<Pixel>,
+}
+
+impl Display for Image {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+impl<'a> FromIterator<&'a str> for Image {
+    fn from_iter<T: IntoIterator<Item = &'a str>>(iter: T) -> Self {
+        let mut image = Image::default();
+
+        for (y, row) in iter.into_iter().enumerate() {
+            for (x, pix) in row.chars().map(Pixel::from).enumerate() {
+                image.grid.set(
+                    Vec2 {
+                        x: x as isize,
+                        y: y as isize,
+                    },
+                    pix,
+                );
+            }
+        }
+        image
+    }
+}
+
+fn nine_grid(pos: Vec2) -> impl Iterator<Item = Vec2> {
+    (-1..=1).flat_map(move |y| (-1..=1).map(move |x| pos + Vec2 { x, y }))
+}
+
+impl Image {
+    fn pixels_lit(&self) -> impl Iterator<Item = Vec2> + '_ {
+        if matches!(self.grid.background(), Pixel::Lit) {
+            panic!("There is infinite number of lit pixels!");
+        }
+        self.grid.iter().map(|(pos, _)| pos)
+    }
+
+    fn neighbors(&self, pos: Vec2) -> impl Iterator<Item = Pixel> + '_ {
+        nine_grid(pos).map(|p| *self.grid.get(p))
+    }
+
+    fn pixel_value(&self, pos: Vec2) -> usize {
+        self.neighbors(pos)
+            .map(|pix| match pix {
+                Pixel::Lit => 1,
+                Pixel::Dark => 0,
+            })
+            .fold(0, |acc, val| (acc << 1) + val)
+    }
+}
+
+/// Enhance the image. The pixels next to the ones that differ from the background
+/// are affected too, so the image grows by one pixel in every direction.
+fn step(image: Image, algo: &Algorithm) -> Image {
+    let mut grid = SparseGrid::new(match image.grid.background() {
+        Pixel::Dark => algo.enchance(0),
+        Pixel::Lit => algo.enchance(511),
+    });
+
+    if let Some((min, max)) = image.grid.bounds() {
+        for y in min.y - 1..=max.y + 1 {
+            for x in min.x - 1..=max.x + 1 {
+                let pos = Vec2 { x, y };
+                grid.set(pos, algo.enchance(image.pixel_value(pos)));
+            }
+        }
+    }
+
+    Image { grid }
+}
+
+fn pixels_lit_after(image: &Image, algo: &Algorithm, steps: usize) -> usize {
+    let mut image = image.clone();
+    for _ in 0..steps {
+        image = step(image, algo);
+    }
+    image.pixels_lit().count()
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 20;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input<'a> = (Algorithm, Image);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let mut lines = input.lines();
+        let algo = Algorithm::from(lines.next().unwrap());
+        let image = Image::from_iter(lines.skip(1));
+        (algo, image)
+    }
+
+    fn part1((algo, image): &Self::Input<'_>) -> Option<usize> {
+        Some(pixels_lit_after(image, algo, 2))
+    }
+
+    fn part2((algo, image): &Self::Input<'_>) -> Option<usize> {
+        Some(pixels_lit_after(image, algo, 50))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_pixel_val() {
+        let algo = Algorithm::from("..#.#..#####.#.#.#.###.##.....###.##.#.");
+        let image = Image::from_iter(["#..#.", "#....", "##..#", "..#..", "..###"]);
+
+        assert_eq!(34, image.pixel_value(Vec2 { x: 2, y: 2 }));
+        assert_eq!(Pixel::Lit, algo.enchance(34));
+    }
+
+    #[test]
+    fn test_part1() {
+        let algo = Algorithm::from(
+            "..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#",
+        );
+        let mut image = Image::from_iter(["#..#.", "#....", "##..#", "..#..", "..###"]);
+
+        for _ in 0..2 {
+            image = step(image, &algo);
+        }
+        assert_eq!(35, image.pixels_lit().count());
+
+        for _ in 2..50 {
+            image = step(image, &algo);
+        }
+        assert_eq!(3351, image.pixels_lit().count());
+    }
+}
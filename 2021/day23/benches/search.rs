@@ -0,0 +1,21 @@
+//! Dijkstra's search against A* with deadlock pruning, on part 2 of the real input.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day23_2021::{solve_with, Day23, Strategy};
+use utils::solution::Solution;
+
+fn bench_search(c: &mut Criterion) {
+    let (_, start) = Day23::parse(Day23::INPUT);
+
+    let mut group = c.benchmark_group("2021-day23-search");
+    group.sample_size(10);
+    for (name, strategy) in [("dijkstra", Strategy::DIJKSTRA), ("astar", Strategy::ASTAR)] {
+        group.bench_function(name, |b| {
+            b.iter(|| solve_with(black_box(start.clone()), strategy))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_search);
+criterion_main!(benches);
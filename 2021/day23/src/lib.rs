@@ -0,0 +1,908 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+use petgraph::algo::all_simple_paths;
+use petgraph::graph::{Graph, NodeIndex};
+use petgraph::Undirected;
+use utils::search;
+use utils::solution::Solution;
+
+/// The amphipods going to the n-th room from the left, `A` for the first one
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Amphipod(u8);
+
+impl Amphipod {
+    fn from_letter(letter: char) -> Option<Self> {
+        letter
+            .is_ascii_uppercase()
+            .then(|| Amphipod(letter as u8 - b'A'))
+    }
+
+    fn letter(&self) -> char {
+        (b'A' + self.0) as char
+    }
+
+    fn move_cost(&self) -> usize {
+        10usize.pow(self.0 as u32)
+    }
+}
+
+impl Display for Amphipod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.letter())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    Hallway,
+    /// The hallway right outside a room, where amphipods can't stop
+    Entrance,
+    Room(Amphipod),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Place {
+    kind: Kind,
+    occupant: Option<Amphipod>,
+    /// The 0-based line and column in the diagram
+    position: (usize, usize),
+}
+
+impl Place {
+    fn new(kind: Kind, occupant: Option<Amphipod>, position: (usize, usize)) -> Self {
+        Self {
+            kind,
+            occupant,
+            position,
+        }
+    }
+
+    fn free(&self) -> bool {
+        self.occupant.is_none()
+    }
+}
+
+impl Display for Kind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct State {
+    graph: Graph<Place, (), Undirected>,
+}
+
+/// All states share the same graph, they differ only in where the amphipods are
+impl PartialEq for State {
+    fn eq(&self, other: &Self) -> bool {
+        self.occupants().eq(other.occupants())
+    }
+}
+
+impl Eq for State {}
+
+impl Hash for State {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for occupant in self.occupants() {
+            occupant.hash(state);
+        }
+    }
+}
+
+impl State {
+    fn occupants(&self) -> impl Iterator<Item = Option<Amphipod>> + '_ {
+        self.graph.node_weights().map(|place| place.occupant)
+    }
+
+    fn room(&self, idx: NodeIndex) -> &Place {
+        &self.graph[idx]
+    }
+
+    fn rooms(&self) -> impl Iterator<Item = &Place> {
+        self.graph
+            .node_weights()
+            .filter(|node| matches!(node.kind, Kind::Room(_)))
+    }
+
+    fn free_hallway_nodes_idx(&self) -> impl Iterator<Item = NodeIndex> + '_ {
+        self.graph.node_indices().filter(|idx| {
+            let room = self.room(*idx);
+            room.kind == Kind::Hallway && room.free()
+        })
+    }
+
+    /// Returns an iterator over the rooms destined for given amphipod
+    fn destination_rooms(
+        &self,
+        amphipod: Amphipod,
+    ) -> impl DoubleEndedIterator<Item = NodeIndex> + '_ {
+        self.graph
+            .node_indices()
+            .filter(move |idx| self.room(*idx).kind == Kind::Room(amphipod))
+    }
+
+    fn is_finished(&self) -> bool {
+        self.rooms().all(|room| {
+            if let Some(occupant) = room.occupant {
+                room.kind == Kind::Room(occupant)
+            } else {
+                // this room is free
+                false
+            }
+        })
+    }
+
+    fn finished_moving(&self, idx: NodeIndex) -> bool {
+        let room = self.room(idx);
+        let this_room_occupant = room.occupant.unwrap();
+        match room.kind {
+            Kind::Hallway | Kind::Entrance => false,
+            Kind::Room(room_kind) => {
+                if this_room_occupant == room_kind {
+                    self.destination_rooms(room_kind).all(|idx| {
+                        let room = self.room(idx);
+                        if let Some(occupant) = room.occupant {
+                            occupant == room_kind
+                        } else {
+                            true
+                        }
+                    })
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
+    // Returns an iterator over all nodes that could be moved
+    fn movable(&self) -> impl Iterator<Item = NodeIndex> + '_ {
+        self.graph
+            .node_indices()
+            .filter(|idx| !self.room(*idx).free())
+            .filter(|idx| !self.finished_moving(*idx))
+    }
+
+    fn can_traverse(&self, path: &[NodeIndex]) -> bool {
+        if let (Some(from), Some(to)) = (path.first(), path.last()) {
+            if self.room(*to).kind == Kind::Entrance {
+                // Amphipod cannot stop at 'crossroads'
+                return false;
+            }
+
+            let amphipod = self.room(*from).occupant.unwrap();
+            if matches!(self.room(*from).kind, Kind::Hallway) {
+                // From hallway you can go to your room only
+                if self.room(*to).kind != Kind::Room(amphipod) {
+                    return false;
+                }
+            }
+            if let Kind::Room(kind) = self.room(*to).kind {
+                // Amphipod can only enter its room.
+                if kind != amphipod
+                    || !self.destination_rooms(amphipod).all(|idx| {
+                        let room = self.room(idx);
+                        if let Some(occupant) = room.occupant {
+                            occupant == amphipod
+                        } else {
+                            true
+                        }
+                    })
+                {
+                    return false;
+                }
+            }
+        }
+        path.iter()
+            .skip(1)
+            .all(|idx| self.graph[*idx].occupant.is_none())
+    }
+
+    fn path_to(&self, from: NodeIndex, to: NodeIndex) -> Option<Vec<NodeIndex>> {
+        let mut paths = all_simple_paths::<Vec<_>, _>(&self.graph, from, to, 1, None);
+        if let Some(path) = paths.next() {
+            if self.can_traverse(&path) {
+                return Some(path);
+            }
+        }
+
+        None
+    }
+
+    fn try_enter_own_room(&self, from: NodeIndex) -> Option<Vec<NodeIndex>> {
+        if let Some(kind) = self.graph[from].occupant {
+            for idx in self.destination_rooms(kind).rev() {
+                if let Some(occupant) = self.room(idx).occupant {
+                    // occupied
+                    if occupant != kind {
+                        return None;
+                    }
+                } else {
+                    // free
+                    if let Some(path) = self.path_to(from, idx) {
+                        return Some(path);
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Move the amphipod along the path, returns the energy it took
+    fn traverse(&mut self, path: Vec<NodeIndex>) -> usize {
+        if let (Some(from), Some(to)) = (path.first(), path.last()) {
+            let amphipod = self.room(*from).occupant.unwrap();
+            self.go(*from, *to);
+            (path.len() - 1) * amphipod.move_cost()
+        } else {
+            0
+        }
+    }
+
+    fn go(&mut self, from: NodeIndex, to: NodeIndex) {
+        assert!(self.room(to).free());
+        self.graph[to].occupant = self.graph[from].occupant;
+        self.graph[from].occupant = None;
+    }
+
+    fn moved_along(&self, path: Vec<NodeIndex>) -> (State, usize) {
+        let mut new_state = self.clone();
+        let cost = new_state.traverse(path);
+        (new_state, cost)
+    }
+
+    /// States after every possible move, with the energy the move takes.
+    /// If an amphipod can go to its room, that's the only move considered,
+    /// as it can only make things cheaper.
+    fn successors(&self) -> Vec<(State, usize)> {
+        let mut successors = vec![];
+        for idx in self.movable() {
+            if let Some(path) = self.try_enter_own_room(idx) {
+                return vec![self.moved_along(path)];
+            }
+            if self.room(idx).kind == Kind::Hallway {
+                continue;
+            }
+            for target_idx in self.free_hallway_nodes_idx() {
+                if let Some(path) = self.path_to(idx, target_idx) {
+                    successors.push(self.moved_along(path));
+                }
+            }
+        }
+        successors
+    }
+}
+
+/// The burrow diagram, with the amphipods where they are now
+impl Display for State {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let places: HashMap<_, _> = self
+            .graph
+            .node_weights()
+            .map(|place| (place.position, place.occupant))
+            .collect();
+        let (lines, columns) = places.keys().fold((0, 0), |(l, c), (line, column)| {
+            (l.max(*line), c.max(*column))
+        });
+        // Walls around the places, including diagonally
+        let is_wall = |line: usize, column: usize| {
+            (line.saturating_sub(1)..=line + 1).any(|l| {
+                (column.saturating_sub(1)..=column + 1).any(|c| places.contains_key(&(l, c)))
+            })
+        };
+        for line in 0..=lines + 1 {
+            let row: String = (0..=columns + 1)
+                .map(|column| match places.get(&(line, column)) {
+                    Some(Some(amphipod)) => amphipod.letter(),
+                    Some(None) => '.',
+                    None if is_wall(line, column) => '#',
+                    None => ' ',
+                })
+                .collect();
+            writeln!(f, "{}", row.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+/// An amphipod going from one place to another, and the energy it takes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub from: NodeIndex,
+    pub to: NodeIndex,
+    pub amphipod: Amphipod,
+    pub cost: usize,
+}
+
+impl Display for Move {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} from {} to {}: {} energy",
+            self.amphipod,
+            self.from.index(),
+            self.to.index(),
+            self.cost
+        )
+    }
+}
+
+impl State {
+    /// The move leading from this state to the next one
+    fn move_to(&self, next: &State) -> Move {
+        let changed = |a: &State, b: &State| {
+            self.graph
+                .node_indices()
+                .find(|idx| a.room(*idx).occupant.is_some() && b.room(*idx).free())
+                .expect("An amphipod moved")
+        };
+        let (from, to) = (changed(self, next), changed(next, self));
+        let amphipod = self.room(from).occupant.unwrap();
+        let path = all_simple_paths::<Vec<_>, _>(&self.graph, from, to, 0, None)
+            .next()
+            .unwrap();
+        Move {
+            from,
+            to,
+            amphipod,
+            cost: (path.len() - 1) * amphipod.move_cost(),
+        }
+    }
+
+    /// The lengths of the shortest paths between all the places, by node index
+    fn distances(&self) -> Vec<Vec<usize>> {
+        self.graph
+            .node_indices()
+            .map(|from| {
+                let lengths = petgraph::algo::dijkstra(&self.graph, from, None, |_| 1);
+                self.graph.node_indices().map(|to| lengths[&to]).collect()
+            })
+            .collect()
+    }
+
+    /// The least energy it could take to organize the amphipods if they could pass
+    /// through each other: every one that has to move takes the shortest way to its
+    /// room, and the ones entering the same room stop at different depths
+    fn lower_bound(&self, distances: &[Vec<usize>]) -> usize {
+        let mut entering: HashMap<Amphipod, usize> = HashMap::new();
+        let mut energy = 0;
+        for idx in self.graph.node_indices() {
+            let Some(amphipod) = self.room(idx).occupant else {
+                continue;
+            };
+            let top = self.destination_rooms(amphipod).next().unwrap();
+            let mut steps = distances[idx.index()][top.index()];
+            if self.room(idx).kind == Kind::Room(amphipod) {
+                let blocking = self
+                    .destination_rooms(amphipod)
+                    .filter(|below| *below > idx)
+                    .any(|below| {
+                        matches!(self.room(below).occupant, Some(other) if other != amphipod)
+                    });
+                if !blocking {
+                    continue;
+                }
+                // Out past the entrance to let the ones below leave, and back in
+                steps += 4;
+            }
+            let depth = entering.entry(amphipod).or_default();
+            energy += (steps + *depth) * amphipod.move_cost();
+            *depth += 1;
+        }
+        energy
+    }
+
+    /// Whether two amphipods in the hallway stand in the way to each other's room,
+    /// so that neither can ever move again
+    fn deadlocked(&self) -> bool {
+        // The columns of the waiting amphipods and of their rooms
+        let waiting: Vec<(usize, usize)> = self
+            .graph
+            .node_indices()
+            .filter(|idx| self.room(*idx).kind == Kind::Hallway)
+            .filter_map(|idx| {
+                let amphipod = self.room(idx).occupant?;
+                let top = self.destination_rooms(amphipod).next().unwrap();
+                Some((self.room(idx).position.1, self.room(top).position.1))
+            })
+            .collect();
+        waiting.iter().any(|(column, room)| {
+            waiting.iter().any(|(other_column, other_room)| {
+                column < other_column && other_column < room && other_room < column
+            })
+        })
+    }
+
+    /// The state after the move
+    pub fn apply(&self, mv: &Move) -> State {
+        let mut next = self.clone();
+        assert_eq!(Some(mv.amphipod), self.room(mv.from).occupant);
+        next.go(mv.from, mv.to);
+        next
+    }
+}
+
+/// What the search for the cheapest organization does on top of Dijkstra's
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Strategy {
+    /// Search with A*, estimating the energy left with [`State::lower_bound`]
+    pub heuristic: bool,
+    /// Skip the states where two amphipods block each other in the hallway
+    pub prune_deadlocks: bool,
+}
+
+impl Strategy {
+    pub const DIJKSTRA: Strategy = Strategy {
+        heuristic: false,
+        prune_deadlocks: false,
+    };
+    pub const ASTAR: Strategy = Strategy {
+        heuristic: true,
+        prune_deadlocks: true,
+    };
+}
+
+/// How much work a search took
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// States whose successors were computed
+    pub expanded: usize,
+    /// Successors skipped as deadlocked
+    pub pruned: usize,
+}
+
+/// The least energy it takes to organize the amphipods and the moves doing it,
+/// `None` if they can't be organized
+pub fn solve(start: State) -> Option<(usize, Vec<Move>)> {
+    solve_with(start, Strategy::ASTAR).0
+}
+
+/// [`solve`] with the given strategy, along with the work it took
+pub fn solve_with(start: State, strategy: Strategy) -> (Option<(usize, Vec<Move>)>, Stats) {
+    let distances = start.distances();
+    let mut stats = Stats::default();
+    let found = search::astar(
+        start,
+        |state| {
+            stats.expanded += 1;
+            let mut successors = state.successors();
+            if strategy.prune_deadlocks {
+                let count = successors.len();
+                successors.retain(|(next, _)| !next.deadlocked());
+                stats.pruned += count - successors.len();
+            }
+            successors
+        },
+        |state| match strategy.heuristic {
+            true => state.lower_bound(&distances),
+            false => 0,
+        },
+        State::is_finished,
+    );
+    let solution = found.map(|(states, cost)| {
+        let moves = states
+            .windows(2)
+            .map(|pair| pair[0].move_to(&pair[1]))
+            .collect();
+        (cost, moves)
+    });
+    (solution, stats)
+}
+
+/// The diagrams of the burrow after each of the moves, each preceded by the move
+pub fn replay(start: &State, moves: &[Move]) -> String {
+    let mut state = start.clone();
+    let mut frames = vec![state.to_string()];
+    for mv in moves {
+        state = state.apply(mv);
+        frames.push(format!("{}\n{}", mv, state));
+    }
+    frames.join("\n")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    /// Line and column are 1-based
+    UnexpectedChar {
+        line: usize,
+        column: usize,
+        found: char,
+    },
+    MissingHallway,
+    MissingRooms,
+    /// The places of a room row aren't below those of the first one,
+    /// or those aren't below the hallway
+    Misaligned {
+        line: usize,
+    },
+    TooManyRooms,
+    /// Not as many amphipods of the kind as there are places in a room
+    WrongCount(Amphipod),
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::UnexpectedChar {
+                line,
+                column,
+                found,
+            } => write!(f, "{}:{}: unexpected `{}`", line, column, found),
+            ParseError::MissingHallway => write!(f, "no hallway"),
+            ParseError::MissingRooms => write!(f, "no rooms"),
+            ParseError::Misaligned { line } => write!(f, "{}: misaligned rooms", line),
+            ParseError::TooManyRooms => write!(f, "more rooms than letters"),
+            ParseError::WrongCount(amphipod) => {
+                write!(f, "{} amphipods don't fill a room", amphipod)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The columns of the places in the line of the diagram, with their occupants
+fn places(line_idx: usize, line: &str) -> Result<Vec<(usize, Option<Amphipod>)>, ParseError> {
+    line.chars()
+        .enumerate()
+        .filter(|(_, c)| !matches!(c, '#' | ' '))
+        .map(|(column, c)| match (c, Amphipod::from_letter(c)) {
+            ('.', _) => Ok((column, None)),
+            (_, Some(amphipod)) => Ok((column, Some(amphipod))),
+            _ => Err(ParseError::UnexpectedChar {
+                line: line_idx + 1,
+                column: column + 1,
+                found: c,
+            }),
+        })
+        .collect()
+}
+
+/// The burrow diagram of the puzzle, with any hallway length, number of rooms
+/// and room depth. The first line is the wall above the hallway.
+impl FromStr for State {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines().enumerate().skip(1);
+        let hallway = match lines.next() {
+            Some((line_idx, line)) => places(line_idx, line)?,
+            None => vec![],
+        };
+        if hallway.is_empty() {
+            return Err(ParseError::MissingHallway);
+        }
+        let columns = |row: &[(usize, Option<Amphipod>)]| -> Vec<usize> {
+            row.iter().map(|(column, _)| *column).collect()
+        };
+        let mut rows: Vec<Vec<_>> = vec![];
+        let mut row_lines = vec![];
+        for (line_idx, line) in lines {
+            let row = places(line_idx, line)?;
+            if row.is_empty() {
+                continue;
+            }
+            let aligned = match rows.first() {
+                Some(first) => columns(&row) == columns(first),
+                None => columns(&row)
+                    .iter()
+                    .all(|column| columns(&hallway).contains(column)),
+            };
+            if !aligned {
+                return Err(ParseError::Misaligned { line: line_idx + 1 });
+            }
+            rows.push(row);
+            row_lines.push(line_idx);
+        }
+        let entrances = columns(rows.first().ok_or(ParseError::MissingRooms)?);
+        if entrances.len() > 26 {
+            return Err(ParseError::TooManyRooms);
+        }
+        for kind in (0..26).map(Amphipod) {
+            let count = hallway
+                .iter()
+                .chain(rows.iter().flatten())
+                .filter(|(_, occupant)| *occupant == Some(kind))
+                .count();
+            let expected = if (kind.0 as usize) < entrances.len() {
+                rows.len()
+            } else {
+                0
+            };
+            if count != expected {
+                return Err(ParseError::WrongCount(kind));
+            }
+        }
+
+        let mut graph = Graph::new_undirected();
+        let mut hallway_nodes = vec![];
+        for (column, occupant) in &hallway {
+            let kind = if entrances.contains(column) {
+                Kind::Entrance
+            } else {
+                Kind::Hallway
+            };
+            if let (Kind::Entrance, Some(amphipod)) = (kind, occupant) {
+                return Err(ParseError::UnexpectedChar {
+                    line: 2,
+                    column: column + 1,
+                    found: amphipod.letter(),
+                });
+            }
+            let node = graph.add_node(Place::new(kind, *occupant, (1, *column)));
+            if let Some(prev) = hallway_nodes.last() {
+                graph.add_edge(*prev, node, ());
+            }
+            hallway_nodes.push(node);
+        }
+        for (room, entrance) in entrances.iter().enumerate() {
+            let entrance = columns(&hallway).iter().position(|c| c == entrance);
+            let mut prev = hallway_nodes[entrance.unwrap()];
+            for (row, line) in rows.iter().zip(&row_lines) {
+                let kind = Kind::Room(Amphipod(room as u8));
+                let (column, occupant) = row[room];
+                let node = graph.add_node(Place::new(kind, occupant, (*line, column)));
+                graph.add_edge(prev, node, ());
+                prev = node;
+            }
+        }
+        Ok(State { graph })
+    }
+}
+
+/// The diagram with the two extra rows of part 2 unfolded below the first room row
+fn unfold(diagram: &str) -> String {
+    let mut lines: Vec<_> = diagram.lines().collect();
+    lines.splice(3..3, ["  #D#C#B#A#", "  #D#B#A#C#"]);
+    lines.join("\n")
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 23;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    /// The burrows of both parts
+    type Input<'a> = (State, State);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        let parse = |diagram: &str| {
+            diagram
+                .parse()
+                .unwrap_or_else(|e| panic!("Invalid burrow: {}", e))
+        };
+        (parse(input), parse(&unfold(input)))
+    }
+
+    fn part1((burrow, _): &Self::Input<'_>) -> Option<usize> {
+        solve(burrow.clone()).map(|(energy, _)| energy)
+    }
+
+    fn part2((_, burrow): &Self::Input<'_>) -> Option<usize> {
+        solve(burrow.clone()).map(|(energy, _)| energy)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn burrow(diagram: &str) -> State {
+        diagram.parse().unwrap()
+    }
+
+    #[test]
+    fn test_entering_own_room() {
+        let (mut state, _) = Day23::parse(Day23::INPUT);
+
+        assert_eq!(None, state.try_enter_own_room(NodeIndex::new(11)));
+
+        state.go(NodeIndex::new(17), NodeIndex::new(9));
+        assert_eq!(None, state.try_enter_own_room(NodeIndex::new(10)));
+
+        state.go(NodeIndex::new(18), NodeIndex::new(1));
+        let path = state.try_enter_own_room(NodeIndex::new(11));
+        assert!(path.is_some());
+        assert_eq!(Some(&NodeIndex::new(18)), path.as_ref().unwrap().last());
+        state.traverse(path.unwrap());
+
+        let path = state.try_enter_own_room(NodeIndex::new(9));
+        assert!(path.is_some());
+        assert_eq!(Some(&NodeIndex::new(17)), path.as_ref().unwrap().last());
+    }
+
+    #[test]
+    fn test_finished() {
+        let state = burrow("#############\n#...........#\n###A#B###\n  #A#B#\n  #####");
+        assert!(state.is_finished());
+    }
+
+    #[test]
+    fn test_finished_moving() {
+        let state = burrow(
+            "#############\n\
+             #AD.........#\n\
+             ###.#B#D#.###\n  \
+             #A#B#C#C#\n  \
+             #########",
+        );
+        let [rooma1, roomb0, roomb1, roomc0, roomc1, roomd1] =
+            [12, 13, 14, 15, 16, 18].map(NodeIndex::new);
+
+        assert!(state.finished_moving(rooma1));
+        assert!(state.finished_moving(roomb0));
+        assert!(state.finished_moving(roomb1));
+        assert!(!state.finished_moving(roomc0));
+        assert!(!state.finished_moving(roomc1));
+        assert!(!state.finished_moving(roomd1));
+        assert!(!state.is_finished());
+    }
+
+    #[test]
+    fn test_parse() {
+        let (part1, part2) = Day23::parse(Day23::INPUT);
+        let entrances: Vec<_> = part1
+            .graph
+            .node_indices()
+            .filter(|idx| part1.room(*idx).kind == Kind::Entrance)
+            .map(NodeIndex::index)
+            .collect();
+        assert_eq!(vec![2, 4, 6, 8], entrances);
+        assert_eq!(11 + 4 * 2, part1.graph.node_count());
+        assert_eq!(11 + 4 * 4, part2.graph.node_count());
+
+        let error = |diagram: &str| diagram.parse::<State>().unwrap_err();
+        assert_eq!(
+            ParseError::UnexpectedChar {
+                line: 3,
+                column: 4,
+                found: 'x'
+            },
+            error("#####\n#...#\n###x#A#\n")
+        );
+        assert_eq!(
+            ParseError::UnexpectedChar {
+                line: 2,
+                column: 2,
+                found: 'A'
+            },
+            error("###\n#A..#\n#.#B#\n")
+        );
+        assert_eq!(
+            ParseError::WrongCount(Amphipod(0)),
+            error("###\n#...#\n#B#B#\n")
+        );
+        assert_eq!(
+            ParseError::WrongCount(Amphipod(2)),
+            error("###\n#..C#\n#B#A#\n")
+        );
+        assert_eq!(
+            ParseError::Misaligned { line: 4 },
+            error("###\n#...#\n#B#A#\n##A#B\n")
+        );
+        assert_eq!(
+            ParseError::Misaligned { line: 3 },
+            error("###\n#...#\n###B#A#\n")
+        );
+        assert_eq!(ParseError::MissingRooms, error("###\n#...#\n#####\n"));
+        assert_eq!(ParseError::MissingHallway, error("###\n"));
+        assert_eq!(
+            "3:4: unexpected `x`",
+            error("#####\n#...#\n###x#A#\n").to_string()
+        );
+    }
+
+    #[test]
+    fn test_variants() {
+        // A waits on the left for B to get to its room
+        let energy = |diagram| solve(burrow(diagram)).map(|(energy, _)| energy);
+        assert_eq!(Some(46), energy("#######\n#.....#\n###B#A###\n  #####"));
+        // Neither can get past the other
+        assert_eq!(None, energy("#####\n#...#\n#B#A#\n#####"));
+        // Three rooms, three deep, and already organized
+        assert_eq!(
+            Some(0),
+            energy("#######\n#.....#\n#A#B#C#\n#A#B#C#\n#A#B#C#\n#######")
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let (part1, part2) = Day23::parse(Day23::INPUT);
+        assert_eq!(Day23::INPUT, part1.to_string());
+        assert_eq!(unfold(Day23::INPUT) + "\n", part2.to_string());
+    }
+
+    #[test]
+    fn test_replay() {
+        let start = burrow("#######\n#.....#\n###B#A###\n  #####");
+        let (energy, moves) = solve(start.clone()).unwrap();
+        assert_eq!(
+            vec![
+                Move {
+                    from: NodeIndex::new(6),
+                    to: NodeIndex::new(1),
+                    amphipod: Amphipod(0),
+                    cost: 4
+                },
+                Move {
+                    from: NodeIndex::new(5),
+                    to: NodeIndex::new(6),
+                    amphipod: Amphipod(1),
+                    cost: 40
+                },
+                Move {
+                    from: NodeIndex::new(1),
+                    to: NodeIndex::new(5),
+                    amphipod: Amphipod(0),
+                    cost: 2
+                },
+            ],
+            moves
+        );
+        assert_eq!(energy, moves.iter().map(|mv| mv.cost).sum::<usize>());
+        assert_eq!(
+            "#######\n#.....#\n###B#A#\n  #####\n\n\
+             A from 6 to 1: 4 energy\n#######\n#.A...#\n###B#.#\n  #####\n\n\
+             B from 5 to 6: 40 energy\n#######\n#.A...#\n###.#B#\n  #####\n\n\
+             A from 1 to 5: 2 energy\n#######\n#.....#\n###A#B#\n  #####\n",
+            replay(&start, &moves)
+        );
+
+        // The moves of the real input add up to the answer
+        let (start, _) = Day23::parse(Day23::INPUT);
+        let (energy, moves) = solve(start.clone()).unwrap();
+        assert_eq!(energy, moves.iter().map(|mv| mv.cost).sum::<usize>());
+        let end = moves.iter().fold(start, |state, mv| state.apply(mv));
+        assert!(end.is_finished());
+    }
+
+    #[test]
+    fn test_lower_bound() {
+        let bound = |diagram| {
+            let state = burrow(diagram);
+            state.lower_bound(&state.distances())
+        };
+        assert_eq!(4 * 10 + 4, bound("#######\n#.....#\n###B#A#\n  #####"));
+        // The A below the B can stay, and that's the way to go
+        let diagram = "#######\n#A....#\n###B#.#\n  #A#B#\n  #####";
+        assert_eq!(40 + 3, bound(diagram));
+        assert_eq!(Some(43), solve(burrow(diagram)).map(|(energy, _)| energy));
+        // The ones above have to let the ones below out, and then go deeper
+        assert_eq!(
+            (4 + 5 + 1) + (50 + 40 + 10),
+            bound("#######\n#.....#\n###A#B#\n  #B#A#\n  #####")
+        );
+    }
+
+    #[test]
+    fn test_deadlocked() {
+        // The B is in the way of the A and the other way around
+        let deadlocked =
+            |hallway| burrow(&format!("#########\n{}\n##.###.##\n#########", hallway)).deadlocked();
+        assert!(deadlocked("#..B.A..#"));
+        assert!(!deadlocked("#..A.B..#"));
+        assert!(!deadlocked("#B.....A#"));
+    }
+
+    #[test]
+    fn test_strategies() {
+        let (start, _) = Day23::parse(Day23::INPUT);
+        let (dijkstra, dijkstra_stats) = solve_with(start.clone(), Strategy::DIJKSTRA);
+        let (astar, astar_stats) = solve_with(start, Strategy::ASTAR);
+        assert_eq!(
+            dijkstra.map(|(energy, _)| energy),
+            astar.map(|(energy, _)| energy)
+        );
+        assert_eq!(0, dijkstra_stats.pruned);
+        assert!(astar_stats.expanded < dijkstra_stats.expanded);
+    }
+}
@@ -0,0 +1,280 @@
+//! ALU programs compiled to bytecode working on an array of registers,
+//! with the operands resolved ahead of time.
+
+use crate::{Instruction, Var};
+
+const REGISTERS: [char; 4] = ['w', 'x', 'y', 'z'];
+
+fn register_index(name: char) -> usize {
+    REGISTERS
+        .iter()
+        .position(|r| *r == name)
+        .unwrap_or_else(|| panic!("Unknown register {}", name))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operand {
+    Register(usize),
+    Literal(isize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Inp(usize),
+    Add(usize, Operand),
+    Mul(usize, Operand),
+    Div(usize, Operand),
+    Mod(usize, Operand),
+    Eql(usize, Operand),
+    /// `mul a 0`, which doesn't depend on the value of `a`
+    Clear(usize),
+}
+
+/// The registers and the index of the next instruction
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct State {
+    pub registers: [isize; 4],
+    pub pc: usize,
+}
+
+impl State {
+    pub fn register(&self, name: char) -> isize {
+        self.registers[register_index(name)]
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Program {
+    ops: Vec<Op>,
+}
+
+impl Program {
+    /// Panics on registers other than `w`, `x`, `y` and `z`
+    pub fn compile(instructions: &[Instruction]) -> Self {
+        let operand = |var| match var {
+            Var::Register(name) => Operand::Register(register_index(name)),
+            Var::Literal(val) => Operand::Literal(val),
+        };
+        let ops = instructions
+            .iter()
+            .map(|instr| match *instr {
+                Instruction::Inp(a) => Op::Inp(register_index(a)),
+                Instruction::Add(a, b) => Op::Add(register_index(a), operand(b)),
+                Instruction::Mul(a, Var::Literal(0)) => Op::Clear(register_index(a)),
+                Instruction::Mul(a, b) => Op::Mul(register_index(a), operand(b)),
+                Instruction::Div(a, b) => Op::Div(register_index(a), operand(b)),
+                Instruction::Mod(a, b) => Op::Mod(register_index(a), operand(b)),
+                Instruction::Eql(a, b) => Op::Eql(register_index(a), operand(b)),
+            })
+            .collect();
+        Self { ops }
+    }
+
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    /// Whether the next instruction reads the input
+    pub fn expects_input(&self, state: &State) -> bool {
+        matches!(self.ops.get(state.pc), Some(Op::Inp(_)))
+    }
+
+    /// Execute the instructions from `state.pc` on, until the program ends
+    /// or needs an input other than the given one.
+    pub fn run(&self, state: &mut State, mut input: Option<isize>) {
+        let regs = &mut state.registers;
+        for op in &self.ops[state.pc..] {
+            let value = |regs: &[isize; 4], operand| match operand {
+                Operand::Register(r) => regs[r],
+                Operand::Literal(val) => val,
+            };
+            match *op {
+                Op::Inp(a) => match input.take() {
+                    Some(input) => regs[a] = input,
+                    None => return,
+                },
+                Op::Add(a, b) => regs[a] += value(regs, b),
+                Op::Mul(a, b) => regs[a] *= value(regs, b),
+                Op::Div(a, b) => regs[a] /= value(regs, b),
+                Op::Mod(a, b) => regs[a] %= value(regs, b),
+                Op::Eql(a, b) => regs[a] = (regs[a] == value(regs, b)) as isize,
+                Op::Clear(a) => regs[a] = 0,
+            }
+            state.pc += 1;
+        }
+    }
+
+    /// Run the whole program with the given inputs
+    pub fn eval(&self, inputs: impl IntoIterator<Item = isize>) -> State {
+        let mut state = State::default();
+        for input in inputs {
+            self.run(&mut state, Some(input));
+        }
+        self.run(&mut state, None);
+        state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Alu, Day24};
+    use utils::solution::Solution;
+
+    /// Xorshift, to generate programs without pulling in a dependency
+    struct Random(u64);
+
+    impl Random {
+        fn next(&mut self, below: usize) -> usize {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            (self.0 % below as u64) as usize
+        }
+
+        /// Starts by reading all registers, so that they're rarely zero
+        fn program(&mut self, len: usize) -> Vec<Instruction> {
+            let mut program = REGISTERS.map(Instruction::Inp).to_vec();
+            program.extend((0..len).map(|_| {
+                let a = REGISTERS[self.next(4)];
+                let op = self.next(6);
+                let b = if self.next(2) == 0 {
+                    Var::Register(REGISTERS[self.next(4)])
+                } else {
+                    let val = self.next(30) as isize + 1;
+                    // The remainder by a negative number is never well defined
+                    let negative = op != 4 && self.next(2) == 0;
+                    Var::Literal(if negative { -val } else { val })
+                };
+                match op {
+                    0 => Instruction::Inp(a),
+                    1 => Instruction::Add(a, b),
+                    2 => Instruction::Mul(a, b),
+                    3 => Instruction::Div(a, b),
+                    4 => Instruction::Mod(a, b),
+                    _ => Instruction::Eql(a, b),
+                }
+            }));
+            program
+        }
+    }
+
+    /// Whether the program never divides by zero, only takes the remainder of
+    /// non-negative numbers by positive ones, as the puzzle requires, and never
+    /// overflows. Both implementations are free to panic on anything else.
+    fn well_defined(instructions: &[Instruction], inputs: &[isize]) -> bool {
+        let mut registers = [0isize; 4];
+        let mut inputs = inputs.iter();
+        let index = |name| REGISTERS.iter().position(|r| *r == name).unwrap();
+        instructions.iter().all(|instr| {
+            let value = |registers: &[isize; 4], var| match var {
+                Var::Register(name) => registers[index(name)],
+                Var::Literal(val) => val,
+            };
+            let (a, result) = match *instr {
+                Instruction::Inp(a) => (a, Some(*inputs.next().unwrap())),
+                Instruction::Add(a, b) => {
+                    (a, registers[index(a)].checked_add(value(&registers, b)))
+                }
+                Instruction::Mul(a, b) => {
+                    (a, registers[index(a)].checked_mul(value(&registers, b)))
+                }
+                Instruction::Div(a, b) => {
+                    (a, registers[index(a)].checked_div(value(&registers, b)))
+                }
+                Instruction::Mod(a, b) => {
+                    let (a_val, b) = (registers[index(a)], value(&registers, b));
+                    (a, (a_val >= 0 && b > 0).then(|| a_val % b))
+                }
+                Instruction::Eql(a, b) => {
+                    let equal = registers[index(a)] == value(&registers, b);
+                    (a, Some(equal as isize))
+                }
+            };
+            result.map(|result| registers[index(a)] = result).is_some()
+        })
+    }
+
+    /// The registers after running the program on the reference interpreter
+    fn interpret(instructions: &[Instruction], inputs: &[isize]) -> [isize; 4] {
+        let mut alu = Alu::default();
+        let mut inputs = inputs.iter();
+        for instr in instructions {
+            let input =
+                matches!(instr, Instruction::Inp(_)).then(|| *inputs.next().unwrap() as usize);
+            alu.exec(*instr, input);
+        }
+        REGISTERS.map(|r| *alu.register(r))
+    }
+
+    fn compiled(instructions: &[Instruction], inputs: &[isize]) -> [isize; 4] {
+        Program::compile(instructions)
+            .eval(inputs.iter().copied())
+            .registers
+    }
+
+    #[test]
+    fn test_matches_interpreter() {
+        let mut random = Random(0x2021_0024);
+        let mut compared = 0;
+        let mut negative_divisors = 0;
+        for _ in 0..2000 {
+            let len = 1 + random.next(20);
+            let instructions = random.program(len);
+            let inputs: Vec<_> = (0..instructions.len())
+                .map(|_| 1 + random.next(9) as isize)
+                .collect();
+            if !well_defined(&instructions, &inputs) {
+                continue;
+            }
+            compared += 1;
+            negative_divisors += instructions
+                .iter()
+                .filter(|instr| matches!(instr, Instruction::Div(_, Var::Literal(b)) if *b < 0))
+                .count();
+            assert_eq!(
+                interpret(&instructions, &inputs),
+                compiled(&instructions, &inputs),
+                "{:?} with {:?}",
+                instructions,
+                inputs
+            );
+        }
+        assert!(
+            compared > 1000,
+            "Only {} programs were well defined",
+            compared
+        );
+        // Division truncates towards zero with negative divisors too
+        assert!(negative_divisors > 100);
+    }
+
+    #[test]
+    fn test_truncation() {
+        let program = Program::compile(&crate::parse_input([
+            "inp x", "div x 4", "inp y", "mod y 4", "inp z", "div z -4", "inp w", "mod w -4",
+        ]));
+        let state = program.eval([-7, -7, 7, 7]);
+        // w, x, y, z
+        assert_eq!([3, -1, -3, -1], state.registers);
+        assert_eq!(program.len(), state.pc);
+    }
+
+    #[test]
+    fn test_monad() {
+        let instructions = Day24::parse(Day24::INPUT);
+        let program = Program::compile(&instructions);
+        let mut random = Random(24);
+        for _ in 0..200 {
+            let inputs: Vec<_> = (0..14).map(|_| 1 + random.next(9) as isize).collect();
+            assert_eq!(
+                interpret(&instructions, &inputs),
+                program.eval(inputs.iter().copied()).registers
+            );
+        }
+    }
+}
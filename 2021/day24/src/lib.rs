@@ -0,0 +1,292 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    str::FromStr,
+};
+
+use bytecode::{Program, State};
+use parse_display::FromStr;
+use symbolic::Analysis;
+use utils::solution::Solution;
+
+pub mod bytecode;
+pub mod symbolic;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Var {
+    Register(char),
+    Literal(isize),
+}
+
+impl FromStr for Var {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(num) = s.parse::<isize>() {
+            Ok(Var::Literal(num))
+        } else {
+            Ok(Var::Register(s.chars().next().unwrap()))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, FromStr, PartialEq)]
+#[display(style = "snake_case")]
+pub enum Instruction {
+    #[display("{} {0}")]
+    Inp(char),
+    #[display("{} {0} {1}")]
+    Add(char, Var),
+    #[display("{} {0} {1}")]
+    Mul(char, Var),
+    #[display("{} {0} {1}")]
+    Div(char, Var),
+    #[display("{} {0} {1}")]
+    Mod(char, Var),
+    #[display("{} {0} {1}")]
+    Eql(char, Var),
+}
+
+fn parse_input<'a>(input: impl IntoIterator<Item = &'a str>) -> Vec<Instruction> {
+    input
+        .into_iter()
+        .map(Instruction::from_str)
+        .collect::<Result<_, _>>()
+        .unwrap()
+}
+
+/// Interpreter of the instructions, see [`bytecode::Program`] for a faster one
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq)]
+pub struct Alu {
+    registers: BTreeMap<char, isize>,
+    pc: usize,
+}
+
+impl Alu {
+    pub fn register(&mut self, name: char) -> &mut isize {
+        self.registers.entry(name).or_default()
+    }
+
+    pub fn exec(&mut self, instr: Instruction, input: Option<usize>) {
+        let mut reg_or_literal = |name| match name {
+            Var::Register(name) => *self.register(name),
+            Var::Literal(val) => val,
+        };
+        match instr {
+            Instruction::Inp(reg) => *self.register(reg) = input.expect("Loading input") as isize,
+            Instruction::Add(a, b) => {
+                *self.register(a) += reg_or_literal(b);
+            }
+            Instruction::Mul(a, b) => {
+                *self.register(a) *= reg_or_literal(b);
+            }
+            Instruction::Div(a, b) => {
+                *self.register(a) /= reg_or_literal(b);
+            }
+            Instruction::Mod(a, b) => {
+                *self.register(a) %= reg_or_literal(b);
+            }
+            Instruction::Eql(a, b) => {
+                let rhs = reg_or_literal(b);
+                *self.register(a) = (*self.register(a) == rhs) as isize;
+            }
+        }
+        self.pc += 1;
+    }
+}
+
+fn find_model_no(
+    program: &Program,
+    inputs: impl Iterator<Item = usize> + Clone,
+    state: State,
+    states: &mut HashSet<State>,
+) -> Option<Vec<usize>> {
+    assert!(program.expects_input(&state));
+
+    if states.contains(&state) {
+        // This ALU state has already been observed *and*
+        // it has not resulted in finding the model no for
+        // any input. We can skip it.
+        return None;
+    }
+
+    for input in inputs.clone() {
+        let mut state = state;
+        program.run(&mut state, Some(input as isize));
+
+        if state.pc == program.len() {
+            // End of program
+            if state.register('z') == 0 {
+                return Some(vec![input]);
+            }
+        } else if let Some(mut digits) = find_model_no(program, inputs.clone(), state, states) {
+            digits.push(input);
+            return Some(digits);
+        }
+        states.insert(state);
+    }
+
+    states.insert(state);
+    None
+}
+
+/// Find the largest or the smallest model number by searching through the digits,
+/// skipping the ALU states that have already been seen.
+/// Slow, see [`symbolic::Analysis`] for an analytical solution.
+pub fn search_model_no(instructions: &[Instruction], largest: bool) -> Option<usize> {
+    let program = Program::compile(instructions);
+    let (state, mut states) = (State::default(), HashSet::new());
+    let digits = if largest {
+        find_model_no(&program, (1..=9).rev(), state, &mut states)
+    } else {
+        find_model_no(&program, 1..=9, state, &mut states)
+    };
+    digits.map(|digits| build_model_no(&digits))
+}
+
+fn build_model_no(digits: &[usize]) -> usize {
+    let mut model_no = 0;
+    for d in digits.iter().rev() {
+        model_no = model_no * 10 + d;
+    }
+    model_no
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 24;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input<'a> = Vec<Instruction>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_input(input.lines())
+    }
+
+    fn part1(instructions: &Self::Input<'_>) -> Option<usize> {
+        Analysis::new(instructions).largest_model_no()
+    }
+
+    fn part2(instructions: &Self::Input<'_>) -> Option<usize> {
+        Analysis::new(instructions).smallest_model_no()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_parsing() {
+        assert_eq!(Ok(Instruction::Inp('a')), "inp a".parse());
+        assert_eq!(
+            Ok(Instruction::Add('a', Var::Register('b'))),
+            "add a b".parse()
+        );
+        assert_eq!(
+            Ok(Instruction::Add('a', Var::Literal(-100))),
+            "add a -100".parse()
+        );
+    }
+
+    #[test]
+    fn test_inp() {
+        let mut alu = Alu::default();
+        assert_eq!(&0, alu.register('a'));
+        alu.exec(Instruction::Inp('a'), Some(100));
+        assert_eq!(&100, alu.register('a'));
+        alu.exec(Instruction::Inp('a'), Some(200));
+        assert_eq!(&200, alu.register('a'));
+        alu.exec(Instruction::Inp('a'), Some(0));
+        assert_eq!(&0, alu.register('a'));
+    }
+
+    #[test]
+    fn test_add() {
+        let mut alu = Alu::default();
+
+        alu.exec(Instruction::Add('a', Var::Literal(100)), None);
+        assert_eq!(&100, alu.register('a'));
+
+        alu.exec(Instruction::Add('b', Var::Literal(50)), None);
+        assert_eq!(&100, alu.register('a'));
+        assert_eq!(&50, alu.register('b'));
+
+        alu.exec(Instruction::Add('a', Var::Register('b')), None);
+        assert_eq!(&150, alu.register('a'));
+        assert_eq!(&50, alu.register('b'));
+    }
+
+    #[test]
+    fn test_eql() {
+        let mut alu = Alu::default();
+
+        alu.exec(Instruction::Eql('a', Var::Literal(0)), None);
+        assert_eq!(&1, alu.register('a'));
+    }
+
+    /// A block of MONAD, checking one digit
+    fn monad_block(div: isize, check: isize, offset: isize) -> String {
+        format!(
+            "inp w\nmul x 0\nadd x z\nmod x 26\ndiv z {}\nadd x {}\neql x w\neql x 0\n\
+             mul y 0\nadd y 25\nmul y x\nadd y 1\nmul z y\nmul y 0\nadd y w\nadd y {}\n\
+             mul y x\nadd z y\n",
+            div, check, offset
+        )
+    }
+
+    fn run(instructions: &[Instruction], model_no: usize) -> isize {
+        let digits = model_no.to_string();
+        let mut digits = digits.chars().map(|d| d.to_digit(10).unwrap() as usize);
+        let mut alu = Alu::default();
+        for instr in instructions {
+            let input = matches!(instr, Instruction::Inp(_)).then(|| digits.next().unwrap());
+            alu.exec(*instr, input);
+        }
+        *alu.register('z')
+    }
+
+    #[test]
+    fn test_symbolic_matches_search() {
+        // d1 - 2 == d2 and d0 + 2 == d3
+        let program = [(1, 12, 3), (1, 11, 7), (26, -9, 1), (26, -1, 2)]
+            .map(|(div, check, offset)| monad_block(div, check, offset))
+            .concat();
+        let instructions = Day24::parse(&program);
+        let analysis = Analysis::new(&instructions);
+        assert_eq!(Some(7979), analysis.largest_model_no());
+        assert_eq!(Some(1313), analysis.smallest_model_no());
+        assert_eq!(
+            search_model_no(&instructions, true),
+            analysis.largest_model_no()
+        );
+        assert_eq!(
+            search_model_no(&instructions, false),
+            analysis.smallest_model_no()
+        );
+
+        let report = analysis.to_string();
+        assert!(report.contains("  d1 - 2 == d2\n"), "{}", report);
+        assert!(report.contains("  d0 + 2 == d3\n"), "{}", report);
+        assert!(
+            report.contains("largest: 7979, smallest: 1313"),
+            "{}",
+            report
+        );
+    }
+
+    #[test]
+    fn test_symbolic_input() {
+        let instructions = Day24::parse(Day24::INPUT);
+        let analysis = Analysis::new(&instructions);
+        let (largest, smallest) = (
+            analysis.largest_model_no().unwrap(),
+            analysis.smallest_model_no().unwrap(),
+        );
+        assert_eq!(0, run(&instructions, largest));
+        assert_eq!(0, run(&instructions, smallest));
+        assert_ne!(0, run(&instructions, largest + 1));
+    }
+}
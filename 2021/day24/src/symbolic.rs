@@ -0,0 +1,451 @@
+//! Symbolic execution of ALU programs.
+//!
+//! Every register holds an expression over the input digits, simplified as it's built
+//! using the bounds of its operands. Comparisons that can go either way fork the
+//! execution, each path remembering what it assumed. The paths on which `z` can end up
+//! being `0` give the constraints on the digits of valid model numbers.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use crate::{Instruction, Var};
+
+/// The values an input digit can have
+const DIGITS: (isize, isize) = (1, 9);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    Const(isize),
+    /// The `n`-th input digit, counted from 0
+    Input(usize),
+    Add(Box<Expr>, Box<Expr>),
+    Mul(Box<Expr>, Box<Expr>),
+    Div(Box<Expr>, Box<Expr>),
+    Mod(Box<Expr>, Box<Expr>),
+    Eql(Box<Expr>, Box<Expr>),
+}
+
+use Expr::Const;
+
+impl Expr {
+    /// The smallest and the largest value the expression can have
+    pub fn bounds(&self) -> (isize, isize) {
+        match self {
+            Const(c) => (*c, *c),
+            Expr::Input(_) => DIGITS,
+            Expr::Add(a, b) => {
+                let ((a_lo, a_hi), (b_lo, b_hi)) = (a.bounds(), b.bounds());
+                (a_lo.saturating_add(b_lo), a_hi.saturating_add(b_hi))
+            }
+            Expr::Mul(a, b) => {
+                let ((a_lo, a_hi), (b_lo, b_hi)) = (a.bounds(), b.bounds());
+                let products = [
+                    a_lo.saturating_mul(b_lo),
+                    a_lo.saturating_mul(b_hi),
+                    a_hi.saturating_mul(b_lo),
+                    a_hi.saturating_mul(b_hi),
+                ];
+                (
+                    *products.iter().min().unwrap(),
+                    *products.iter().max().unwrap(),
+                )
+            }
+            Expr::Div(a, b) => {
+                let (lo, hi) = a.bounds();
+                match **b {
+                    // Truncating division by a constant is monotonic
+                    Const(d) if d > 0 => (lo / d, hi / d),
+                    Const(d) if d < 0 => (hi / d, lo / d),
+                    _ => {
+                        let max = lo.saturating_abs().max(hi.saturating_abs());
+                        (-max, max)
+                    }
+                }
+            }
+            Expr::Mod(a, b) => {
+                let ((lo, hi), (b_lo, b_hi)) = (a.bounds(), b.bounds());
+                let max = b_lo.saturating_abs().max(b_hi.saturating_abs()) - 1;
+                // The remainder has the sign of the dividend
+                if lo >= 0 {
+                    (0, max.min(hi))
+                } else if hi <= 0 {
+                    (-max.min(-lo), 0)
+                } else {
+                    (-max, max)
+                }
+            }
+            Expr::Eql(_, _) => (0, 1),
+        }
+    }
+
+    /// The value of the expression for the given input digits
+    pub fn eval(&self, digits: &[isize]) -> isize {
+        match self {
+            Const(c) => *c,
+            Expr::Input(n) => digits[*n],
+            Expr::Add(a, b) => a.eval(digits) + b.eval(digits),
+            Expr::Mul(a, b) => a.eval(digits) * b.eval(digits),
+            Expr::Div(a, b) => a.eval(digits) / b.eval(digits),
+            Expr::Mod(a, b) => a.eval(digits) % b.eval(digits),
+            Expr::Eql(a, b) => (a.eval(digits) == b.eval(digits)) as isize,
+        }
+    }
+
+    /// The input digits the expression depends on
+    fn inputs(&self, inputs: &mut Vec<usize>) {
+        match self {
+            Const(_) => {}
+            Expr::Input(n) => {
+                if !inputs.contains(n) {
+                    inputs.push(*n)
+                }
+            }
+            Expr::Add(a, b)
+            | Expr::Mul(a, b)
+            | Expr::Div(a, b)
+            | Expr::Mod(a, b)
+            | Expr::Eql(a, b) => {
+                a.inputs(inputs);
+                b.inputs(inputs);
+            }
+        }
+    }
+
+    /// Split `q * d + r` with `0 <= r < d` into `q` and `r`, if `q * d + r` can't be negative
+    fn div_rem(&self, d: isize) -> Option<(&Expr, Option<&Expr>)> {
+        match self {
+            Expr::Mul(q, m) if **m == Const(d) && q.bounds().0 >= 0 => Some((q, None)),
+            Expr::Add(a, r) => match a.div_rem(d) {
+                Some((q, None)) if r.bounds().0 >= 0 && r.bounds().1 < d => Some((q, Some(r))),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn precedence(&self) -> u8 {
+        match self {
+            Expr::Eql(_, _) => 0,
+            Expr::Add(_, _) => 1,
+            Expr::Mul(_, _) | Expr::Div(_, _) | Expr::Mod(_, _) => 2,
+            Const(_) | Expr::Input(_) => 3,
+        }
+    }
+}
+
+fn add(a: Expr, b: Expr) -> Expr {
+    match (a, b) {
+        (Const(a), Const(b)) => Const(a + b),
+        (Const(0), e) | (e, Const(0)) => e,
+        (Const(c), e) => add(e, Const(c)),
+        (Expr::Add(e, c), Const(d)) if matches!(*c, Const(_)) => add(*e, add(*c, Const(d))),
+        (a, b) => Expr::Add(Box::new(a), Box::new(b)),
+    }
+}
+
+fn mul(a: Expr, b: Expr) -> Expr {
+    match (a, b) {
+        (Const(a), Const(b)) => Const(a * b),
+        (Const(0), _) | (_, Const(0)) => Const(0),
+        (Const(1), e) | (e, Const(1)) => e,
+        (Const(c), e) => mul(e, Const(c)),
+        (a, b) => Expr::Mul(Box::new(a), Box::new(b)),
+    }
+}
+
+fn div(a: Expr, b: Expr) -> Expr {
+    match (a, b) {
+        (_, Const(0)) => panic!("Division by zero"),
+        (Const(a), Const(b)) => Const(a / b),
+        (e, Const(1)) => e,
+        (a, Const(d)) if d > 0 && a.bounds().0 > -d && a.bounds().1 < d => Const(0),
+        (a, Const(d)) if d > 0 && a.div_rem(d).is_some() => a.div_rem(d).unwrap().0.clone(),
+        (a, b) => Expr::Div(Box::new(a), Box::new(b)),
+    }
+}
+
+fn rem(a: Expr, b: Expr) -> Expr {
+    match (a, b) {
+        (_, Const(0)) => panic!("Modulo by zero"),
+        (Const(a), Const(b)) => Const(a % b),
+        (a, Const(d)) if d > 0 && a.bounds().0 >= 0 && a.bounds().1 < d => a,
+        (a, Const(d)) if d > 0 && a.div_rem(d).is_some() => match a.div_rem(d).unwrap().1 {
+            Some(r) => r.clone(),
+            None => Const(0),
+        },
+        (a, b) => Expr::Mod(Box::new(a), Box::new(b)),
+    }
+}
+
+fn eql(a: Expr, b: Expr) -> Expr {
+    let ((a_lo, a_hi), (b_lo, b_hi)) = (a.bounds(), b.bounds());
+    if a == b {
+        Const(1)
+    } else if a_hi < b_lo || b_hi < a_lo {
+        Const(0)
+    } else if let (Const(a), Const(b)) = (&a, &b) {
+        Const((a == b) as isize)
+    } else {
+        Expr::Eql(Box::new(a), Box::new(b))
+    }
+}
+
+impl Display for Expr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (a, op, b) = match self {
+            Const(c) => return write!(f, "{}", c),
+            Expr::Input(n) => return write!(f, "d{}", n),
+            Expr::Add(a, b) => match **b {
+                Const(c) if c < 0 => return write!(f, "{} - {}", a, -c),
+                _ => (a, "+", b),
+            },
+            Expr::Mul(a, b) => (a, "*", b),
+            Expr::Div(a, b) => (a, "/", b),
+            Expr::Mod(a, b) => (a, "%", b),
+            Expr::Eql(a, b) => (a, "==", b),
+        };
+        // Operators are left-associative
+        if a.precedence() < self.precedence() {
+            write!(f, "({})", a)?;
+        } else {
+            write!(f, "{}", a)?;
+        }
+        if b.precedence() <= self.precedence() {
+            write!(f, " {} ({})", op, b)
+        } else {
+            write!(f, " {} {}", op, b)
+        }
+    }
+}
+
+/// An assumption about the input digits made on a path of the execution
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Constraint {
+    pub lhs: Expr,
+    pub rhs: Expr,
+    pub equal: bool,
+}
+
+impl Constraint {
+    fn holds(&self, digits: &[isize]) -> bool {
+        (self.lhs.eval(digits) == self.rhs.eval(digits)) == self.equal
+    }
+
+    fn inputs(&self) -> Vec<usize> {
+        let mut inputs = vec![];
+        self.lhs.inputs(&mut inputs);
+        self.rhs.inputs(&mut inputs);
+        inputs.sort_unstable();
+        inputs
+    }
+}
+
+impl Display for Constraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let op = if self.equal { "==" } else { "!=" };
+        write!(f, "{} {} {}", self.lhs, op, self.rhs)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Path {
+    registers: BTreeMap<char, Expr>,
+    inputs: usize,
+    constraints: Vec<Constraint>,
+}
+
+impl Path {
+    fn register(&self, name: char) -> Expr {
+        self.registers.get(&name).cloned().unwrap_or(Const(0))
+    }
+
+    /// Execute the instruction, forking the path if a comparison can go either way
+    fn exec(mut self, instr: Instruction, paths: &mut Vec<Path>) {
+        let value = |var| match var {
+            Var::Register(name) => self.register(name),
+            Var::Literal(val) => Const(val),
+        };
+        let (reg, result) = match instr {
+            Instruction::Inp(reg) => {
+                self.inputs += 1;
+                (reg, Expr::Input(self.inputs - 1))
+            }
+            Instruction::Add(a, b) => (a, add(self.register(a), value(b))),
+            Instruction::Mul(a, b) => (a, mul(self.register(a), value(b))),
+            Instruction::Div(a, b) => (a, div(self.register(a), value(b))),
+            Instruction::Mod(a, b) => (a, rem(self.register(a), value(b))),
+            Instruction::Eql(a, b) => (a, eql(self.register(a), value(b))),
+        };
+
+        if let Expr::Eql(lhs, rhs) = result {
+            let assumed = self
+                .constraints
+                .iter()
+                .find(|c| c.lhs == *lhs && c.rhs == *rhs)
+                .map(|c| c.equal);
+            for equal in [true, false] {
+                if assumed.is_some_and(|assumed| assumed != equal) {
+                    continue;
+                }
+                let mut path = self.clone();
+                path.registers.insert(reg, Const(equal as isize));
+                if assumed.is_none() {
+                    path.constraints.push(Constraint {
+                        lhs: (*lhs).clone(),
+                        rhs: (*rhs).clone(),
+                        equal,
+                    });
+                }
+                paths.push(path);
+            }
+        } else {
+            self.registers.insert(reg, result);
+            paths.push(self);
+        }
+    }
+
+    /// The lexicographically largest or smallest digits satisfying the constraints.
+    ///
+    /// Digits related by constraints are brute-forced together, so this is exponential
+    /// only in the size of the largest group of related digits.
+    fn solve(&self, largest: bool) -> Option<Vec<isize>> {
+        let (best, worst) = if largest {
+            (DIGITS.1, DIGITS.0)
+        } else {
+            (DIGITS.0, DIGITS.1)
+        };
+        let mut digits = vec![best; self.inputs];
+
+        // Group the digits related by the constraints
+        let mut groups: Vec<(Vec<usize>, Vec<&Constraint>)> = vec![];
+        for constraint in &self.constraints {
+            let mut inputs = constraint.inputs();
+            let mut constraints = vec![constraint];
+            groups.retain(|(group, group_constraints)| {
+                if group.iter().any(|n| inputs.contains(n)) {
+                    inputs.extend(group);
+                    constraints.extend(group_constraints);
+                    false
+                } else {
+                    true
+                }
+            });
+            inputs.sort_unstable();
+            inputs.dedup();
+            groups.push((inputs, constraints));
+        }
+
+        for (inputs, constraints) in groups {
+            // Count through the assignments from the best one, the first digit being the most significant
+            inputs.iter().for_each(|n| digits[*n] = best);
+            loop {
+                if constraints.iter().all(|c| c.holds(&digits)) {
+                    break;
+                }
+                let last_moving = inputs.iter().rev().find(|n| digits[**n] != worst)?;
+                let step = if largest { -1 } else { 1 };
+                digits[*last_moving] += step;
+                inputs
+                    .iter()
+                    .filter(|n| *n > last_moving)
+                    .for_each(|n| digits[*n] = best);
+            }
+        }
+        Some(digits)
+    }
+}
+
+/// The constraints on the input digits under which a program ends with `z == 0`
+pub struct Analysis {
+    /// The constraints of every path of the execution on which `z` can end up being `0`
+    paths: Vec<Path>,
+}
+
+impl Analysis {
+    pub fn new(program: &[Instruction]) -> Self {
+        let mut paths = vec![Path {
+            registers: BTreeMap::new(),
+            inputs: 0,
+            constraints: vec![],
+        }];
+        for instr in program {
+            let mut next = Vec::with_capacity(paths.len());
+            for path in paths {
+                path.exec(*instr, &mut next);
+            }
+            paths = next;
+        }
+
+        paths.retain_mut(|path| {
+            let z = path.register('z');
+            let (lo, hi) = z.bounds();
+            if lo > 0 || hi < 0 {
+                return false;
+            }
+            if z != Const(0) {
+                path.constraints.push(Constraint {
+                    lhs: z,
+                    rhs: Const(0),
+                    equal: true,
+                });
+            }
+            true
+        });
+        Self { paths }
+    }
+
+    /// The largest or the smallest accepted model number
+    fn model_no(&self, largest: bool) -> Option<usize> {
+        let numbers = self.paths.iter().filter_map(|path| {
+            let digits = path.solve(largest)?;
+            Some(digits.iter().fold(0, |acc, d| acc * 10 + *d as usize))
+        });
+        if largest {
+            numbers.max()
+        } else {
+            numbers.min()
+        }
+    }
+
+    pub fn largest_model_no(&self) -> Option<usize> {
+        self.model_no(true)
+    }
+
+    pub fn smallest_model_no(&self) -> Option<usize> {
+        self.model_no(false)
+    }
+}
+
+impl Display for Analysis {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{} path(s) can end with z = 0", self.paths.len())?;
+        for (idx, path) in self.paths.iter().enumerate() {
+            writeln!(f, "Path {}:", idx + 1)?;
+            for constraint in &path.constraints {
+                writeln!(f, "  {}", constraint)?;
+            }
+            let free: Vec<_> = (0..path.inputs)
+                .filter(|n| path.constraints.iter().all(|c| !c.inputs().contains(n)))
+                .map(|n| format!("d{}", n))
+                .collect();
+            if !free.is_empty() {
+                writeln!(f, "  unconstrained: {}", free.join(", "))?;
+            }
+            match (path.solve(true), path.solve(false)) {
+                (Some(largest), Some(smallest)) => {
+                    let show = |digits: Vec<isize>| {
+                        digits.iter().map(|d| d.to_string()).collect::<String>()
+                    };
+                    writeln!(
+                        f,
+                        "  largest: {}, smallest: {}",
+                        show(largest),
+                        show(smallest)
+                    )?
+                }
+                _ => writeln!(f, "  no digits satisfy the constraints")?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+use std::fmt::Display;
+
+use utils::solution::Solution;
+use utils::Grid;
+
+extern crate utils;
+
+type Vec2 = utils::Vec2<isize>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Empty,
+    EastFacing,
+    SouthFacing,
+}
+
+impl From<char> for Cell {
+    fn from(c: char) -> Self {
+        match c {
+            '>' => Cell::EastFacing,
+            'v' => Cell::SouthFacing,
+            '.' => Cell::Empty,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Cell::Empty => '.',
+            Cell::EastFacing => '>',
+            Cell::SouthFacing => 'v',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SeaFloor {
+    grid: Grid<Cell>,
+}
+
+impl<'a> FromIterator<&'a str> for SeaFloor {
+    fn from_iter<T: IntoIterator<Item = &'a str>>(iter: T) -> Self {
+        Self {
+            grid: Grid::from_iter(iter),
+        }
+    }
+}
+
+impl Display for SeaFloor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+impl SeaFloor {
+    /// Move the whole herd facing the given direction at once
+    fn move_herd(&mut self, herd: Cell, dir: Vec2) -> bool {
+        let grid = &self.grid;
+        let moving = grid
+            .iter()
+            .filter(|(pos, cell)| **cell == herd && *grid.get_wrapped(*pos + dir) == Cell::Empty)
+            .map(|(pos, _)| pos)
+            .collect::<Vec<_>>();
+
+        for pos in &moving {
+            self.grid[*pos] = Cell::Empty;
+            let new_pos = self.grid.wrap(*pos + dir);
+            self.grid[new_pos] = herd;
+        }
+        !moving.is_empty()
+    }
+
+    fn step(&mut self) -> bool {
+        let east_moved = self.move_herd(Cell::EastFacing, Vec2 { x: 1, y: 0 });
+        let south_moved = self.move_herd(Cell::SouthFacing, Vec2 { x: 0, y: 1 });
+        east_moved || south_moved
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 25;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input<'a> = SeaFloor;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        SeaFloor::from_iter(input.lines())
+    }
+
+    fn part1(sea_floor: &Self::Input<'_>) -> Option<usize> {
+        let mut sea_floor = sea_floor.clone();
+        (1..).find(|_| !sea_floor.step())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::SeaFloor;
+
+    #[test]
+    fn test() {
+        let input = [
+            "...>...", ".......", "......>", "v.....>", "......>", ".......", "..vvv..",
+        ];
+
+        let mut sea_floor = SeaFloor::from_iter(input);
+        let mut step = 0;
+        loop {
+            step += 1;
+            let changed = sea_floor.step();
+
+            println!("After {} step:", step);
+            println!("{}", sea_floor);
+            if !changed || step == 58 {
+                break;
+            }
+        }
+        assert_eq!(58, step);
+    }
+}
@@ -0,0 +1,77 @@
+use utils::solution::Solution;
+
+fn find_best_pos_and_cost<F: Fn(usize) -> usize>(
+    positions: &[usize],
+    cost_function: F,
+) -> (usize, usize) {
+    let max_pos = *positions.iter().max().unwrap();
+
+    // Search all possible positions for the cheapest one
+    (0..=max_pos)
+        .map(|pos| {
+            (
+                pos,
+                positions.iter().fold(0, |acc, &crab_pos| {
+                    acc + cost_function(crab_pos.abs_diff(pos))
+                }),
+            )
+        })
+        .min_by_key(|(_, cost)| *cost)
+        .unwrap()
+}
+
+fn solve_part1(positions: &[usize]) -> (usize, usize) {
+    find_best_pos_and_cost(positions, |dist| dist)
+}
+
+fn solve_part2(positions: &[usize]) -> (usize, usize) {
+    find_best_pos_and_cost(positions, |dist| ((1 + dist) * dist) / 2)
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 7;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input<'a> = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .next()
+            .unwrap()
+            .split(',')
+            .map(|v| v.parse::<usize>().unwrap())
+            .collect()
+    }
+
+    fn part1(positions: &Self::Input<'_>) -> Option<usize> {
+        let (_best_pos, fuel_cost) = solve_part1(positions);
+        Some(fuel_cost)
+    }
+
+    fn part2(positions: &Self::Input<'_>) -> Option<usize> {
+        let (_best_pos, fuel_cost) = solve_part2(positions);
+        Some(fuel_cost)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_calc_point() {
+        let positions = [16, 1, 2, 0, 4, 2, 7, 1, 2, 14];
+        assert_eq!((2, 37), solve_part1(&positions));
+    }
+
+    #[test]
+    fn test_part2() {
+        let positions = [16, 1, 2, 0, 4, 2, 7, 1, 2, 14];
+        assert_eq!((5, 168), solve_part2(&positions));
+    }
+}
@@ -0,0 +1,134 @@
+use std::collections::VecDeque;
+
+use itertools::Itertools;
+use utils::solution::Solution;
+use utils::Grid;
+
+extern crate utils;
+type Vec2 = utils::Vec2<isize>;
+
+#[derive(Debug)]
+pub struct HeatMap {
+    map: Grid<u32>,
+}
+
+impl<'a> FromIterator<&'a str> for HeatMap {
+    fn from_iter<T: IntoIterator<Item = &'a str>>(iter: T) -> Self {
+        Self {
+            map: Grid::from_rows(
+                iter.into_iter()
+                    .map(|l| l.chars().map(|c| c.to_digit(10).unwrap())),
+            ),
+        }
+    }
+}
+
+impl HeatMap {
+    fn get(&self, pos: Vec2) -> Option<u32> {
+        self.map.get(pos).copied()
+    }
+
+    fn neighbors(&self, pos: Vec2) -> impl Iterator<Item = Vec2> + '_ {
+        self.map.neighbors4(pos)
+    }
+
+    fn neighbors_values(&self, pos: Vec2) -> impl Iterator<Item = u32> + '_ {
+        self.neighbors(pos).map(|p| self.map[p])
+    }
+
+    fn is_low_point(&self, pos: Vec2) -> bool {
+        let point = self.get(pos).unwrap();
+        self.neighbors_values(pos).all(|n| n > point)
+    }
+
+    fn low_points(&self) -> impl Iterator<Item = Vec2> + '_ {
+        self.map.positions().filter(|pos| self.is_low_point(*pos))
+    }
+
+    fn low_points_vals(&self) -> impl Iterator<Item = u32> + '_ {
+        self.low_points().map(|pos| self.get(pos).unwrap())
+    }
+
+    /// Get a basin for the given position
+    fn get_basin(&self, pos: Vec2) -> Vec<Vec2> {
+        let mut res = vec![];
+        let mut queue = VecDeque::<Vec2>::from([pos]);
+
+        while let Some(pos) = queue.pop_front() {
+            res.push(pos);
+            let pos_val = self.get(pos).unwrap();
+
+            queue.extend(self.neighbors(pos).filter(|n_pos| {
+                let n_pos_val = self.get(*n_pos).unwrap();
+                n_pos_val != 9 && n_pos_val > pos_val
+            }));
+        }
+
+        res.into_iter().unique().collect()
+    }
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const YEAR: u16 = 2021;
+    const DAY: u8 = 9;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input<'a> = HeatMap;
+    type Answer1 = u32;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        HeatMap::from_iter(input.lines())
+    }
+
+    fn part1(map: &Self::Input<'_>) -> Option<u32> {
+        Some(map.low_points_vals().map(|val| val + 1).sum())
+    }
+
+    fn part2(map: &Self::Input<'_>) -> Option<usize> {
+        Some(
+            map.low_points()
+                .map(|pos| map.get_basin(pos).len())
+                .sorted()
+                .rev()
+                .take(3)
+                .product(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: [&str; 5] = [
+        "2199943210",
+        "3987894921",
+        "9856789892",
+        "8767896789",
+        "9899965678",
+    ];
+
+    #[test]
+    fn test_part1() {
+        let map = HeatMap::from_iter(INPUT);
+        let low_points = map.low_points_vals().collect::<Vec<u32>>();
+        assert_eq!(15, low_points.iter().map(|val| val + 1).sum::<u32>());
+    }
+
+    #[test]
+    fn test_part2() {
+        let map = HeatMap::from_iter(INPUT);
+        let res: usize = map
+            .low_points()
+            .map(|pos| map.get_basin(pos).len())
+            .sorted()
+            .rev()
+            .take(3)
+            .product();
+
+        assert_eq!(1134, res);
+    }
+}
@@ -0,0 +1,40 @@
+use itertools::Itertools;
+use utils::solution::Solution;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 1;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    /// Calories carried by every elf, the biggest first
+    type Input<'a> = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .group_by(|l| l.is_empty())
+            .into_iter()
+            .filter_map(|(empty, elf)| {
+                if !empty {
+                    Some(elf.map(|e| e.parse::<usize>().unwrap()).sum())
+                } else {
+                    None
+                }
+            })
+            .sorted()
+            .rev()
+            .collect()
+    }
+
+    fn part1(elves: &Self::Input<'_>) -> Option<usize> {
+        elves.first().copied()
+    }
+
+    fn part2(elves: &Self::Input<'_>) -> Option<usize> {
+        Some(elves.iter().take(3).sum())
+    }
+}
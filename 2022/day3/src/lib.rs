@@ -0,0 +1,90 @@
+use utils::solution::Solution;
+
+fn priority_of_item(item: char) -> u32 {
+    if item.is_ascii_lowercase() {
+        (item as u32) - 96
+    } else {
+        (item as u32) - 38
+    }
+}
+
+fn part1(lines: &[&str]) -> u32 {
+    lines
+        .iter()
+        .map(|line| line.split_at(line.len() / 2))
+        .map(|(a, b)| {
+            let item = a.chars().find(|e| b.contains(*e)).unwrap();
+            priority_of_item(item)
+        })
+        .sum()
+}
+
+fn part2(lines: &[&str]) -> u32 {
+    lines
+        .chunks(3)
+        .map(|group| {
+            let item = group[0]
+                .chars()
+                .find(|e| group[1].contains(*e) && group[2].contains(*e))
+                .unwrap();
+            priority_of_item(item)
+        })
+        .sum()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 3;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input<'a> = Vec<&'a str>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input.lines().collect()
+    }
+
+    fn part1(lines: &Self::Input<'_>) -> Option<u32> {
+        Some(part1(lines))
+    }
+
+    fn part2(lines: &Self::Input<'_>) -> Option<u32> {
+        Some(part2(lines))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{part1, part2};
+
+    #[test]
+    fn test_part1() {
+        let input = [
+            "vJrwpWtwJgWrhcsFMMfFFhFp",
+            "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+            "PmmdzqPrVvPwwTWBwg",
+            "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn",
+            "ttgJtRGJQctTZtZT",
+            "CrZsJsPPZsGzwwsLwLmpwMDw",
+        ];
+
+        assert_eq!(157, part1(&input));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = [
+            "vJrwpWtwJgWrhcsFMMfFFhFp",
+            "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL",
+            "PmmdzqPrVvPwwTWBwg",
+            "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn",
+            "ttgJtRGJQctTZtZT",
+            "CrZsJsPPZsGzwwsLwLmpwMDw",
+        ];
+
+        assert_eq!(70, part2(&input));
+    }
+}
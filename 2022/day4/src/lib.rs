@@ -0,0 +1,87 @@
+use parse_display::{Display, FromStr};
+use utils::solution::Solution;
+
+#[derive(Display, FromStr, Clone, Copy, Debug, PartialEq)]
+#[display("{from}-{to}")]
+pub struct Assignment {
+    from: u32,
+    to: u32,
+}
+
+impl Assignment {
+    fn contains(self, other: Assignment) -> bool {
+        self.from <= other.from && self.to >= other.to
+    }
+
+    fn overlaps_with(self, other: Assignment) -> bool {
+        self.from <= other.to && self.to >= other.to || other.from <= self.to && other.to >= self.to
+    }
+}
+
+#[derive(Display, FromStr, Clone, Copy, Debug, PartialEq)]
+#[display("{0},{1}")]
+pub struct Pair(Assignment, Assignment);
+
+fn find_fully_containing(pairs: impl Iterator<Item = Pair>) -> usize {
+    pairs
+        .filter(|p| p.0.contains(p.1) || p.1.contains(p.0))
+        .count()
+}
+
+fn find_overlapping(pairs: impl Iterator<Item = Pair>) -> usize {
+    pairs.filter(|p| p.0.overlaps_with(p.1)).count()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 4;
+    const INPUT: &'static str = include_str!("input.txt");
+
+    type Input<'a> = Vec<Pair>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+            .lines()
+            .map(|line| line.parse::<Pair>().unwrap())
+            .collect()
+    }
+
+    fn part1(pairs: &Self::Input<'_>) -> Option<usize> {
+        Some(find_fully_containing(pairs.iter().copied()))
+    }
+
+    fn part2(pairs: &Self::Input<'_>) -> Option<usize> {
+        Some(find_overlapping(pairs.iter().copied()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{find_overlapping, Assignment, Pair};
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Ok(Pair(
+                Assignment { from: 2, to: 4 },
+                Assignment { from: 6, to: 8 }
+            )),
+            "2-4,6-8".parse()
+        )
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = &[
+            "2-4,6-8", "2-3,4-5", "5-7,7-9", "2-8,3-7", "6-6,4-6", "2-6,4-8",
+        ];
+        assert_eq!(
+            4,
+            find_overlapping(input.iter().map(|l| l.parse().unwrap()))
+        );
+    }
+}
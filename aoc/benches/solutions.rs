@@ -0,0 +1,176 @@
+//! Benchmarks of parsing and both parts of every day, on the real inputs.
+//!
+//! Besides the usual criterion arguments (a filter like `2021-day15`,
+//! `--save-baseline <name>`, `--baseline <name>`), `AOC_INPUT_DIR` can point
+//! to a directory to take the inputs from.
+//!
+//! When done, a summary with the mean time of every benchmark is printed,
+//! compared against the baseline if one was given.
+
+use std::cell::OnceCell;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use aoc::registry;
+use criterion::{black_box, Criterion, SamplingMode};
+use utils::input::InputSource;
+use utils::solution::Part;
+
+const BENCHES: [&str; 3] = ["parse", "part1", "part2"];
+
+/// Where criterion keeps the results, following its own lookup
+fn output_directory() -> PathBuf {
+    if let Some(dir) = std::env::var_os("CRITERION_HOME") {
+        return dir.into();
+    }
+    match std::env::var_os("CARGO_TARGET_DIR") {
+        Some(dir) => Path::new(&dir).join("criterion"),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("../target/criterion"),
+    }
+}
+
+fn group_name(year: u16, day: u8) -> String {
+    format!("{}-day{}", year, day)
+}
+
+fn bench_all(c: &mut Criterion) {
+    for solver in registry::solutions() {
+        let (year, day) = (solver.year(), solver.day());
+        let input = InputSource::from_env()
+            .load(year, day, solver.input())
+            .unwrap_or_else(|e| panic!("Failed to read the input of {} day {}: {}", year, day, e));
+
+        let mut group = c.benchmark_group(group_name(year, day));
+        group.sampling_mode(SamplingMode::Flat).sample_size(10);
+
+        group.bench_function("parse", |b| b.iter(|| solver.parse(black_box(&input))));
+
+        // Parsed only once a part passes the filter
+        let parsed = OnceCell::new();
+        for (name, part) in [("part1", Part::One), ("part2", Part::Two)] {
+            group.bench_function(name, |b| {
+                let parsed = parsed.get_or_init(|| solver.parse(&input));
+                b.iter(|| parsed.solve(black_box(part)))
+            });
+        }
+        group.finish();
+    }
+}
+
+/// Mean time in nanoseconds recorded by criterion in `<bench>/<dir>/estimates.json`
+fn mean(bench: &Path, dir: &str, since: Option<SystemTime>) -> Option<f64> {
+    let path = bench.join(dir).join("estimates.json");
+    if let Some(since) = since {
+        // Skip results left over from previous runs
+        if std::fs::metadata(&path).ok()?.modified().ok()? < since {
+            return None;
+        }
+    }
+    let estimates: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(path).ok()?).ok()?;
+    estimates["mean"]["point_estimate"].as_f64()
+}
+
+/// Times of parse, part1 and part2 of a day
+type Row = [Option<f64>; 3];
+
+fn total(row: &Row) -> Option<f64> {
+    row.iter().flatten().copied().reduce(|a, b| a + b)
+}
+
+fn ms(ns: Option<f64>) -> String {
+    ns.map_or("-".to_string(), |ns| format!("{:.4}", ns / 1e6))
+}
+
+fn change(new: Option<f64>, base: Option<f64>) -> String {
+    match (new, base) {
+        (Some(new), Some(base)) if base > 0.0 => format!("{:+.1}%", (new - base) / base * 100.0),
+        _ => "-".to_string(),
+    }
+}
+
+fn print_summary(dir: &Path, started: SystemTime, baseline: Option<&str>) {
+    let mut years: BTreeMap<u16, Vec<(u8, Row, Row)>> = BTreeMap::new();
+    for solver in registry::solutions() {
+        let (year, day) = (solver.year(), solver.day());
+        let group = dir.join(group_name(year, day));
+        let new = BENCHES.map(|bench| mean(&group.join(bench), "new", Some(started)));
+        if new.iter().all(Option::is_none) {
+            continue;
+        }
+        let base = BENCHES
+            .map(|bench| baseline.and_then(|baseline| mean(&group.join(bench), baseline, None)));
+        years.entry(year).or_default().push((day, new, base));
+    }
+    if years.is_empty() {
+        return;
+    }
+
+    println!();
+    print!(
+        "{:<12} {:>10} {:>10} {:>10} {:>10}",
+        "day (ms)", "parse", "part1", "part2", "total"
+    );
+    match baseline {
+        Some(baseline) => println!(" {:>10} {:>8}", baseline, "change"),
+        None => println!(),
+    }
+    for (year, days) in years {
+        let (mut year_new, mut year_base) = (0.0, 0.0);
+        for (day, new, base) in days {
+            let (new_total, base_total) = (total(&new), total(&base));
+            year_new += new_total.unwrap_or_default();
+            year_base += base_total.unwrap_or_default();
+
+            print!(
+                "{:<12} {:>10} {:>10} {:>10} {:>10}",
+                group_name(year, day),
+                ms(new[0]),
+                ms(new[1]),
+                ms(new[2]),
+                ms(new_total)
+            );
+            match baseline {
+                Some(_) => println!(
+                    " {:>10} {:>8}",
+                    ms(base_total),
+                    change(new_total, base_total)
+                ),
+                None => println!(),
+            }
+        }
+        print!(
+            "{:<12} {:>43}",
+            format!("{} total", year),
+            ms(Some(year_new))
+        );
+        match baseline {
+            Some(_) => println!(
+                " {:>10} {:>8}",
+                ms(Some(year_base)),
+                change(Some(year_new), Some(year_base))
+            ),
+            None => println!(),
+        }
+    }
+}
+
+/// The baseline to compare against, as given with `--baseline[-lenient] <name>`
+fn baseline_arg() -> Option<String> {
+    let mut args =
+        std::env::args().skip_while(|arg| arg != "--baseline" && arg != "--baseline-lenient");
+    args.next()?;
+    args.next()
+}
+
+fn main() {
+    // Leave some slack for file systems with coarse modification times
+    let started = SystemTime::now() - Duration::from_secs(1);
+    let mut c = Criterion::default().configure_from_args();
+
+    bench_all(&mut c);
+    c.final_summary();
+
+    print_summary(&output_directory(), started, baseline_arg().as_deref());
+}
@@ -0,0 +1,200 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::Deserialize;
+use utils::solution::Part;
+
+/// Default location of the answer files
+pub const ANSWERS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers");
+
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[serde(untagged)]
+enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Answer {
+    fn normalized(&self) -> String {
+        match self {
+            Answer::Number(n) => n.to_string(),
+            Answer::Text(text) => normalize(text),
+        }
+    }
+}
+
+/// Make multi-line answers (e.g. letters drawn with `█`) comparable:
+/// trailing whitespace and surrounding empty lines are not significant.
+fn normalize(answer: &str) -> String {
+    answer
+        .lines()
+        .map(str::trim_end)
+        .skip_while(|l| l.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim_end()
+        .to_string()
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct DayAnswers {
+    part1: Option<Answer>,
+    part2: Option<Answer>,
+}
+
+/// Outcome of checking an answer against the recorded one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+        actual: Option<String>,
+    },
+    /// There is no recorded answer
+    Missing {
+        actual: Option<String>,
+    },
+}
+
+/// Recorded answers, one `<year>.toml` file per year:
+///
+/// ```toml
+/// [day1]
+/// part1 = 1715
+/// part2 = "1739"
+/// ```
+#[derive(Debug, Default)]
+pub struct Ledger {
+    years: BTreeMap<u16, BTreeMap<String, DayAnswers>>,
+}
+
+impl Ledger {
+    /// Load all `<year>.toml` files from the given directory
+    pub fn load(dir: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut ledger = Ledger::default();
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            let Some(year) = path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .and_then(|s| s.parse().ok())
+                .filter(|_| path.extension().is_some_and(|ext| ext == "toml"))
+            else {
+                continue;
+            };
+            let content = std::fs::read_to_string(&path)?;
+            ledger
+                .add_year(year, &content)
+                .map_err(|e| format!("{}: {}", path.display(), e))?;
+        }
+        Ok(ledger)
+    }
+
+    fn add_year(&mut self, year: u16, content: &str) -> Result<(), toml::de::Error> {
+        self.years.insert(year, toml::from_str(content)?);
+        Ok(())
+    }
+
+    fn expected(&self, year: u16, day: u8, part: Part) -> Option<&Answer> {
+        let answers = self.years.get(&year)?.get(&format!("day{}", day))?;
+        match part {
+            Part::One => answers.part1.as_ref(),
+            Part::Two => answers.part2.as_ref(),
+        }
+    }
+
+    pub fn verify(&self, year: u16, day: u8, part: Part, actual: Option<String>) -> Verdict {
+        match self.expected(year, day, part) {
+            None => Verdict::Missing { actual },
+            Some(expected) => {
+                let expected = expected.normalized();
+                if actual.as_deref().map(normalize).as_ref() == Some(&expected) {
+                    Verdict::Pass
+                } else {
+                    Verdict::Fail { expected, actual }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ANSWERS: &str = r#"
+[day1]
+part1 = 1715
+part2 = "1739"
+
+[day13]
+part1 = 729
+part2 = '''
+█  █
+████
+'''
+"#;
+
+    fn ledger() -> Ledger {
+        let mut ledger = Ledger::default();
+        ledger.add_year(2021, ANSWERS).unwrap();
+        ledger
+    }
+
+    #[test]
+    fn test_verify() {
+        let ledger = ledger();
+        assert_eq!(
+            Verdict::Pass,
+            ledger.verify(2021, 1, Part::One, Some("1715".into()))
+        );
+        assert_eq!(
+            Verdict::Pass,
+            ledger.verify(2021, 1, Part::Two, Some("1739".into()))
+        );
+        assert_eq!(
+            Verdict::Fail {
+                expected: "1715".into(),
+                actual: Some("1716".into())
+            },
+            ledger.verify(2021, 1, Part::One, Some("1716".into()))
+        );
+        assert_eq!(
+            Verdict::Fail {
+                expected: "1715".into(),
+                actual: None
+            },
+            ledger.verify(2021, 1, Part::One, None)
+        );
+        assert_eq!(
+            Verdict::Missing {
+                actual: Some("1".into())
+            },
+            ledger.verify(2021, 2, Part::One, Some("1".into()))
+        );
+        assert_eq!(
+            Verdict::Missing { actual: None },
+            ledger.verify(2020, 1, Part::One, None)
+        );
+    }
+
+    #[test]
+    fn test_verify_multiline() {
+        assert_eq!(
+            Verdict::Pass,
+            ledger().verify(2021, 13, Part::Two, Some("\n█  █ \n████ ".into()))
+        );
+    }
+
+    #[test]
+    fn test_invalid_answers() {
+        assert!(Ledger::default()
+            .add_year(2021, "[day1]\npart1 = [1, 2]")
+            .is_err());
+    }
+
+    #[test]
+    fn test_recorded_answers_parse() {
+        Ledger::load(Path::new(ANSWERS_DIR)).unwrap();
+    }
+}
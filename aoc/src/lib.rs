@@ -0,0 +1,2 @@
+pub mod answers;
+pub mod registry;
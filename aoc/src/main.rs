@@ -0,0 +1,202 @@
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use aoc::answers::{self, Ledger, Verdict};
+use aoc::registry;
+use clap::{Parser, Subcommand};
+use utils::fetch;
+use utils::input::{self, InputSource};
+use utils::solution::Part;
+
+#[derive(Parser)]
+#[command(about = "Run Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve the puzzle of the given day
+    Run {
+        year: u16,
+        day: u8,
+        /// Solve only the given part (1 or 2)
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Read the input from the given file (`-` for stdin) instead of the
+        /// AOC_INPUT_DIR directory or the input embedded in the binary
+        #[arg(long)]
+        input: Option<String>,
+    },
+    /// Download the input of the given day into the cache (requires AOC_SESSION)
+    Fetch {
+        year: u16,
+        day: u8,
+        /// Download the puzzle description too
+        #[arg(long)]
+        puzzle: bool,
+    },
+    /// Check the answers of all solutions against the recorded ones, on the
+    /// AOC_INPUT_DIR inputs or the embedded ones, never downloading any
+    Verify {
+        /// Only the given year
+        year: Option<u16>,
+        /// Only the given day
+        day: Option<u8>,
+        /// Directory with `<year>.toml` answer files
+        #[arg(long, default_value = answers::ANSWERS_DIR)]
+        answers: PathBuf,
+    },
+}
+
+fn parts(part: Option<u8>) -> Vec<Part> {
+    match part {
+        Some(1) => vec![Part::One],
+        Some(2) => vec![Part::Two],
+        _ => vec![Part::One, Part::Two],
+    }
+}
+
+/// Load the input from the given source. If there is none and a session is
+/// configured, the input is downloaded (or taken from the cache) rather than
+/// falling back to the embedded one.
+fn load_input(
+    source: InputSource,
+    year: u16,
+    day: u8,
+    embedded: &'static str,
+) -> Result<Cow<'static, str>, Box<dyn std::error::Error>> {
+    let missing = match &source {
+        InputSource::Embedded => true,
+        InputSource::Dir(dir) => !input::path_in(dir, year, day).exists(),
+        InputSource::File(_) | InputSource::Stdin => false,
+    };
+    if missing {
+        if let Some(fetcher) = fetch::from_env() {
+            return Ok(Cow::Owned(fetcher.input(year, day)?));
+        }
+    }
+    Ok(source.load(year, day, embedded)?)
+}
+
+fn verify(year: Option<u16>, day: Option<u8>, answers: &Path) -> ExitCode {
+    let ledger = match Ledger::load(answers) {
+        Ok(ledger) => ledger,
+        Err(e) => {
+            eprintln!("Failed to load the answers: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for solver in registry::solutions()
+        .into_iter()
+        .filter(|s| year.is_none_or(|y| s.year() == y) && day.is_none_or(|d| s.day() == d))
+    {
+        let (year, day) = (solver.year(), solver.day());
+        // Never downloaded: the recorded answers are for the inputs in the repository
+        let input = match InputSource::from_env().load(year, day, solver.input()) {
+            Ok(input) => input,
+            Err(e) => {
+                println!("{} day {}: failed to read the input: {}", year, day, e);
+                failed += 1;
+                continue;
+            }
+        };
+
+        let input = solver.parse(&input);
+        for part in [Part::One, Part::Two] {
+            let status = match ledger.verify(year, day, part, input.solve(part)) {
+                Verdict::Pass => {
+                    passed += 1;
+                    "ok".to_string()
+                }
+                Verdict::Fail { expected, actual } => {
+                    failed += 1;
+                    match actual {
+                        Some(actual) => format!("FAIL (expected {}, got {})", expected, actual),
+                        None => format!("FAIL (expected {}, not solved)", expected),
+                    }
+                }
+                Verdict::Missing {
+                    actual: Some(actual),
+                } => {
+                    missing += 1;
+                    format!("missing (got {})", actual)
+                }
+                Verdict::Missing { actual: None } => "not solved".to_string(),
+            };
+            println!("{} day {} part {}: {}", year, day, part, status);
+        }
+    }
+
+    println!("{} passed, {} failed, {} missing", passed, failed, missing);
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn main() -> ExitCode {
+    match Cli::parse().command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+        } => {
+            let Some(solver) = registry::find(year, day) else {
+                eprintln!("No solution for {} day {}", year, day);
+                return ExitCode::FAILURE;
+            };
+
+            let source = InputSource::from_arg_or_env(input.as_deref());
+            let input = match load_input(source, year, day, solver.input()) {
+                Ok(input) => input,
+                Err(e) => {
+                    eprintln!("Failed to read the input: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let input = solver.parse(&input);
+            for part in parts(part) {
+                match input.solve(part) {
+                    Some(answer) => println!("PART{}: {}", part, answer),
+                    None => println!("PART{}: not solved", part),
+                }
+            }
+        }
+        Command::Fetch { year, day, puzzle } => {
+            let Some(fetcher) = fetch::from_env() else {
+                eprintln!("Set {} to the session cookie first", fetch::SESSION_VAR);
+                return ExitCode::FAILURE;
+            };
+
+            let mut fetched = vec![fetcher
+                .input(year, day)
+                .map(|_| fetcher.input_path(year, day))];
+            if puzzle {
+                fetched.push(
+                    fetcher
+                        .puzzle(year, day)
+                        .map(|_| fetcher.puzzle_path(year, day)),
+                );
+            }
+            for result in fetched {
+                match result {
+                    Ok(path) => println!("{}", path.display()),
+                    Err(e) => {
+                        eprintln!("Failed to fetch: {}", e);
+                        return ExitCode::FAILURE;
+                    }
+                }
+            }
+        }
+        Command::Verify { year, day, answers } => return verify(year, day, &answers),
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,76 @@
+use utils::solution::{solver, Solver};
+
+/// All the solutions, ordered by year and day
+pub fn solutions() -> Vec<Box<dyn Solver>> {
+    vec![
+        solver::<day1_2020::Day1>(),
+        solver::<day2_2020::Day2>(),
+        solver::<day3_2020::Day3>(),
+        solver::<day4_2020::Day4>(),
+        solver::<day5_2020::Day5>(),
+        solver::<day6_2020::Day6>(),
+        solver::<day7_2020::Day7>(),
+        solver::<day8_2020::Day8>(),
+        solver::<day9_2020::Day9>(),
+        solver::<day10_2020::Day10>(),
+        solver::<day11_2020::Day11>(),
+        solver::<day12_2020::Day12>(),
+        solver::<day1_2021::Day1>(),
+        solver::<day2_2021::Day2>(),
+        solver::<day3_2021::Day3>(),
+        solver::<day4_2021::Day4>(),
+        solver::<day5_2021::Day5>(),
+        solver::<day6_2021::Day6>(),
+        solver::<day7_2021::Day7>(),
+        solver::<day8_2021::Day8>(),
+        solver::<day9_2021::Day9>(),
+        solver::<day10_2021::Day10>(),
+        solver::<day11_2021::Day11>(),
+        solver::<day12_2021::Day12>(),
+        solver::<day13_2021::Day13>(),
+        solver::<day14_2021::Day14>(),
+        solver::<day15_2021::Day15>(),
+        solver::<day16_2021::Day16>(),
+        solver::<day17_2021::Day17>(),
+        solver::<day18_2021::Day18>(),
+        solver::<day19_2021::Day19>(),
+        solver::<day20_2021::Day20>(),
+        solver::<day21_2021::Day21>(),
+        solver::<day22_2021::Day22>(),
+        solver::<day23_2021::Day23>(),
+        solver::<day24_2021::Day24>(),
+        solver::<day25_2021::Day25>(),
+        solver::<day1_2022::Day1>(),
+        solver::<day2_2022::Day2>(),
+        solver::<day3_2022::Day3>(),
+        solver::<day4_2022::Day4>(),
+    ]
+}
+
+/// Find the solution for the given day
+pub fn find(year: u16, day: u8) -> Option<Box<dyn Solver>> {
+    solutions()
+        .into_iter()
+        .find(|s| s.year() == year && s.day() == day)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_solutions_are_sorted_and_unique() {
+        let days = solutions()
+            .iter()
+            .map(|s| (s.year(), s.day()))
+            .collect::<Vec<_>>();
+        assert!(days.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_find() {
+        let solver = find(2021, 1).unwrap();
+        assert_eq!((2021, 1), (solver.year(), solver.day()));
+        assert!(find(2021, 26).is_none());
+    }
+}
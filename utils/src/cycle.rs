@@ -0,0 +1,201 @@
+//! Cycle detection in sequences of states `x0, x1 = step(x0), x2 = step(x1), ...`
+//! like generations of a simulation.
+//!
+//! A simulation that settles down is a cycle with a period of 1.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// From the `start`-th state on, the states repeat every `period` steps
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The earliest generation with the same state as the `n`-th one
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.period
+        }
+    }
+}
+
+fn nth_step<S>(mut state: S, step: &mut impl FnMut(&S) -> S, n: usize) -> S {
+    for _ in 0..n {
+        state = step(&state);
+    }
+    state
+}
+
+/// Floyd's tortoise and hare. Keeps only two states in memory,
+/// but computes every state up to three times.
+///
+/// Returns the cycle and the first state in it. Never returns if there's no cycle.
+pub fn floyd<S: Clone + PartialEq>(initial: S, mut step: impl FnMut(&S) -> S) -> (Cycle, S) {
+    // Find a state inside the cycle, at a distance from x0 that's a multiple of the period
+    let mut tortoise = step(&initial);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+
+    // Going from x0 and from there at the same pace, they meet at the start of the cycle
+    let mut start = 0;
+    tortoise = initial;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    let mut period = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        period += 1;
+    }
+
+    (Cycle { start, period }, tortoise)
+}
+
+/// Brent's algorithm. Like [`floyd`] keeps only two states in memory,
+/// but usually computes fewer of them.
+///
+/// Returns the cycle and the first state in it. Never returns if there's no cycle.
+pub fn brent<S: Clone + PartialEq>(initial: S, mut step: impl FnMut(&S) -> S) -> (Cycle, S) {
+    // Look for the period in windows of growing powers of two
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    // With the hare a period ahead, they meet at the start of the cycle
+    let mut start = 0;
+    tortoise = initial.clone();
+    hare = nth_step(initial, &mut step, period);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    (Cycle { start, period }, tortoise)
+}
+
+/// Remembers all states until one repeats. Computes every state once.
+///
+/// Returns the cycle and all states before it repeats, so that
+/// `history[cycle.reduce(n)]` is the `n`-th state. Never returns if there's no cycle.
+pub fn detect<S: Clone + Eq + Hash>(initial: S, mut step: impl FnMut(&S) -> S) -> (Cycle, Vec<S>) {
+    let mut seen = HashMap::new();
+    let mut history = vec![];
+    let mut state = initial;
+    loop {
+        if let Some(&start) = seen.get(&state) {
+            let period = history.len() - start;
+            return (Cycle { start, period }, history);
+        }
+        seen.insert(state.clone(), history.len());
+        let next = step(&state);
+        history.push(state);
+        state = next;
+    }
+}
+
+/// The `n`-th state. If the states start repeating before that,
+/// the rest of the simulation is skipped.
+pub fn nth<S: Clone + Eq + Hash>(initial: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+    let mut seen = HashMap::new();
+    let mut history = vec![];
+    let mut state = initial;
+    while history.len() < n {
+        if let Some(&start) = seen.get(&state) {
+            let cycle = Cycle {
+                start,
+                period: history.len() - start,
+            };
+            return history.swap_remove(cycle.reduce(n));
+        }
+        seen.insert(state.clone(), history.len());
+        let next = step(&state);
+        history.push(state);
+        state = next;
+    }
+    state
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// 0, 1, 2, 3, 4, 5, 6, 3, 4, 5, 6, 3, ...
+    fn step(x: &u32) -> u32 {
+        if *x == 6 {
+            3
+        } else {
+            x + 1
+        }
+    }
+
+    const CYCLE: Cycle = Cycle {
+        start: 3,
+        period: 4,
+    };
+
+    #[test]
+    fn test_floyd_and_brent() {
+        assert_eq!((CYCLE, 3), floyd(0, step));
+        assert_eq!((CYCLE, 3), brent(0, step));
+        assert_eq!(
+            (
+                Cycle {
+                    start: 0,
+                    period: 4
+                },
+                5
+            ),
+            brent(5, step)
+        );
+
+        // Settling down
+        let settled = Cycle {
+            start: 10,
+            period: 1,
+        };
+        assert_eq!((settled, 10), floyd(0, |x: &u32| (x + 1).min(10)));
+        assert_eq!((settled, 10), brent(0, |x: &u32| (x + 1).min(10)));
+    }
+
+    #[test]
+    fn test_detect() {
+        let (cycle, history) = detect(0, step);
+        assert_eq!(CYCLE, cycle);
+        assert_eq!(vec![0, 1, 2, 3, 4, 5, 6], history);
+        assert_eq!(5, history[cycle.reduce(1_000_000_001)]);
+    }
+
+    #[test]
+    fn test_nth() {
+        for n in 0..20 {
+            assert_eq!(nth_step(0, &mut step, n), nth(0, step, n), "n = {}", n);
+        }
+        assert_eq!(4, nth(0, step, 1_000_000_000));
+        // No cycle within the first n
+        assert_eq!(1000, nth(0, |x: &u32| x + 1, 1000));
+    }
+}
@@ -0,0 +1,335 @@
+use std::fmt::Display;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::input;
+
+/// Environment variable with the session cookie of an Advent of Code account
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Environment variable overriding the location of the cache
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+#[derive(Debug)]
+pub enum FetchError {
+    /// Reading from or writing to the cache failed
+    Io(io::Error),
+    /// The server responded with an error
+    Status { url: String, status: u16 },
+    /// The request didn't reach the server or the response couldn't be read
+    Transport(String),
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchError::Io(e) => write!(f, "cache error: {}", e),
+            FetchError::Status { url, status } => write!(f, "GET {} failed with {}", url, status),
+            FetchError::Transport(e) => write!(f, "transport error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+impl From<io::Error> for FetchError {
+    fn from(e: io::Error) -> Self {
+        FetchError::Io(e)
+    }
+}
+
+/// The bit of HTTP needed to talk to the Advent of Code website
+pub trait HttpClient {
+    /// GET the given URL, authenticating with the session cookie
+    fn get(&self, url: &str, session: &str) -> Result<String, FetchError>;
+}
+
+/// Serves responses from files in a directory, named after the URL path.
+/// `<dir>/2021/day/1/input` is the response for `<any host>/2021/day/1/input`
+/// and, like on a static server, `<dir>/2021/day/1/index.html` for `<any host>/2021/day/1`.
+pub struct FixtureClient {
+    dir: PathBuf,
+}
+
+impl FixtureClient {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+}
+
+impl HttpClient for FixtureClient {
+    fn get(&self, url: &str, _session: &str) -> Result<String, FetchError> {
+        // Skip "scheme://host/"
+        let mut path = self.dir.join(url.splitn(4, '/').nth(3).unwrap_or_default());
+        if path.is_dir() {
+            path.push("index.html");
+        }
+        match std::fs::read_to_string(path) {
+            Ok(body) => Ok(body),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Err(FetchError::Status {
+                url: url.to_string(),
+                status: 404,
+            }),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// The real thing
+#[cfg(feature = "fetch")]
+pub struct UreqClient {
+    agent: ureq::Agent,
+}
+
+#[cfg(feature = "fetch")]
+impl Default for UreqClient {
+    fn default() -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new()
+                .user_agent("github.com/poszu/advent-of-code")
+                .build(),
+        }
+    }
+}
+
+#[cfg(feature = "fetch")]
+impl HttpClient for UreqClient {
+    fn get(&self, url: &str, session: &str) -> Result<String, FetchError> {
+        match self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={}", session))
+            .call()
+        {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(status, _)) => Err(FetchError::Status {
+                url: url.to_string(),
+                status,
+            }),
+            Err(ureq::Error::Transport(e)) => Err(FetchError::Transport(e.to_string())),
+        }
+    }
+}
+
+/// Downloaded files kept on disk, keyed by the session, year and day
+pub struct Cache {
+    root: PathBuf,
+}
+
+/// FNV-1a, so that the session key stays the same between builds
+fn session_key(session: &str) -> String {
+    let hash = session.bytes().fold(0xcbf29ce484222325u64, |hash, b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+impl Cache {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// The directory from [`CACHE_DIR_VAR`] or `~/.cache/aoc`
+    pub fn from_env() -> Option<Self> {
+        match std::env::var_os(CACHE_DIR_VAR) {
+            Some(dir) if !dir.is_empty() => Some(Self::new(dir)),
+            _ => {
+                std::env::var_os("HOME").map(|home| Self::new(Path::new(&home).join(".cache/aoc")))
+            }
+        }
+    }
+
+    /// Directory with the files downloaded with the given session.
+    /// The inputs inside are laid out like in an [`input::INPUT_DIR_VAR`] directory.
+    pub fn session_dir(&self, session: &str) -> PathBuf {
+        self.root.join(session_key(session))
+    }
+
+    pub fn input_path(&self, session: &str, year: u16, day: u8) -> PathBuf {
+        input::path_in(&self.session_dir(session), year, day)
+    }
+
+    pub fn puzzle_path(&self, session: &str, year: u16, day: u8) -> PathBuf {
+        self.session_dir(session)
+            .join(year.to_string())
+            .join(format!("day{}.html", day))
+    }
+}
+
+/// Gets inputs and puzzle descriptions, downloading only what's not in the cache yet
+pub struct Fetcher<C> {
+    client: C,
+    cache: Cache,
+    session: String,
+    base_url: String,
+}
+
+impl<C: HttpClient> Fetcher<C> {
+    pub fn new(client: C, cache: Cache, session: impl Into<String>) -> Self {
+        Self {
+            client,
+            cache,
+            session: session.into(),
+            base_url: BASE_URL.to_string(),
+        }
+    }
+
+    /// Talk to a different server, e.g. a local stub
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    pub fn input_path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache.input_path(&self.session, year, day)
+    }
+
+    pub fn puzzle_path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache.puzzle_path(&self.session, year, day)
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        self.cached_or_get(
+            &self.input_path(year, day),
+            &format!("{}/{}/day/{}/input", self.base_url, year, day),
+        )
+    }
+
+    /// The HTML page with the puzzle description
+    pub fn puzzle(&self, year: u16, day: u8) -> Result<String, FetchError> {
+        self.cached_or_get(
+            &self.puzzle_path(year, day),
+            &format!("{}/{}/day/{}", self.base_url, year, day),
+        )
+    }
+
+    fn cached_or_get(&self, path: &Path, url: &str) -> Result<String, FetchError> {
+        match std::fs::read_to_string(path) {
+            Ok(cached) => return Ok(cached),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e.into()),
+        }
+
+        let body = self.client.get(url, &self.session)?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, &body)?;
+        Ok(body)
+    }
+}
+
+/// A fetcher using the session from [`SESSION_VAR`], if it's set
+#[cfg(feature = "fetch")]
+pub fn from_env() -> Option<Fetcher<UreqClient>> {
+    let session = std::env::var(SESSION_VAR).ok().filter(|s| !s.is_empty())?;
+    Some(Fetcher::new(
+        UreqClient::default(),
+        Cache::from_env()?,
+        session,
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_fetch_fills_cache() {
+        let dir = temp_dir("fixture");
+        let fixtures = dir.join("fixtures");
+        std::fs::create_dir_all(fixtures.join("2021/day/1")).unwrap();
+        std::fs::write(fixtures.join("2021/day/1/input"), "199\n200\n").unwrap();
+        std::fs::write(fixtures.join("2021/day/1/index.html"), "<main></main>").unwrap();
+
+        let fetcher = Fetcher::new(
+            FixtureClient::new(&fixtures),
+            Cache::new(dir.join("cache")),
+            "abc",
+        );
+        assert_eq!("199\n200\n", fetcher.input(2021, 1).unwrap());
+        assert!(fetcher.input_path(2021, 1).exists());
+        assert_eq!("<main></main>", fetcher.puzzle(2021, 1).unwrap());
+        assert!(matches!(
+            fetcher.input(2021, 2),
+            Err(FetchError::Status { status: 404, .. })
+        ));
+
+        // Served from the cache from now on
+        std::fs::remove_dir_all(&fixtures).unwrap();
+        assert_eq!("199\n200\n", fetcher.input(2021, 1).unwrap());
+
+        // Other sessions have their own inputs
+        let other = Fetcher::new(
+            FixtureClient::new(&fixtures),
+            Cache::new(dir.join("cache")),
+            "xyz",
+        );
+        assert_ne!(fetcher.input_path(2021, 1), other.input_path(2021, 1));
+        assert!(other.input(2021, 1).is_err());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_session_key_is_stable() {
+        assert_eq!("cbf29ce484222325", session_key(""));
+        assert_eq!("af63dc4c8601ec8c", session_key("a"));
+    }
+
+    #[cfg(feature = "fetch")]
+    #[test]
+    fn test_ureq_client_against_stub_server() {
+        use std::io::{BufRead, BufReader, Write};
+        use std::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let server = std::thread::spawn(move || {
+            let mut requests = vec![];
+            for stream in listener.incoming().take(2) {
+                let mut stream = stream.unwrap();
+                let request = BufReader::new(&stream)
+                    .lines()
+                    .map(Result::unwrap)
+                    .take_while(|l| !l.is_empty())
+                    .collect::<Vec<_>>();
+                let response = match request[0].as_str() {
+                    "GET /2021/day/1/input HTTP/1.1" => {
+                        "HTTP/1.1 200 OK\r\nContent-Length: 4\r\n\r\n199\n"
+                    }
+                    _ => "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n",
+                };
+                stream.write_all(response.as_bytes()).unwrap();
+                requests.push(request);
+            }
+            requests
+        });
+
+        let dir = temp_dir("stub");
+        let fetcher =
+            Fetcher::new(UreqClient::default(), Cache::new(&dir), "abc").with_base_url(&base_url);
+        assert_eq!("199\n", fetcher.input(2021, 1).unwrap());
+        assert!(matches!(
+            fetcher.puzzle(2021, 1),
+            Err(FetchError::Status { status: 404, .. })
+        ));
+        // Cached, doesn't hit the server
+        assert_eq!("199\n", fetcher.input(2021, 1).unwrap());
+
+        let requests = server.join().unwrap();
+        assert!(requests[0]
+            .iter()
+            .any(|h| h == "cookie: session=abc" || h == "Cookie: session=abc"));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
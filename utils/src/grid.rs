@@ -0,0 +1,328 @@
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+
+use crate::{all_directions, orthogonal_directions, Vec2};
+
+/// A dense, rectangular 2D grid stored row by row.
+///
+/// Positions are `Vec2<isize>` with `x` growing to the right and `y` downwards,
+/// so that neighbours of the cells on the edges can be computed without underflows.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// A grid filled with the given value
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// A grid with every cell computed from its position
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Vec2<isize>) -> T) -> Self {
+        Self {
+            cells: (0..height as isize)
+                .flat_map(|y| (0..width as isize).map(move |x| Vec2 { x, y }))
+                .map(&mut f)
+                .collect(),
+            width,
+            height,
+        }
+    }
+
+    /// Build a grid out of rows of cells. Panics if the rows differ in length.
+    pub fn from_rows<R: IntoIterator<Item = T>>(rows: impl IntoIterator<Item = R>) -> Self {
+        let mut grid = Self {
+            cells: vec![],
+            width: 0,
+            height: 0,
+        };
+        for row in rows {
+            grid.cells.extend(row);
+            if grid.height == 0 {
+                grid.width = grid.cells.len();
+            }
+            grid.height += 1;
+            assert_eq!(
+                grid.width * grid.height,
+                grid.cells.len(),
+                "Row {} has a different length than the previous ones",
+                grid.height - 1
+            );
+        }
+        grid
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The size as a vector, e.g. to compute the bottom-right corner
+    pub fn size(&self) -> Vec2<isize> {
+        Vec2 {
+            x: self.width as isize,
+            y: self.height as isize,
+        }
+    }
+
+    pub fn contains(&self, pos: Vec2<isize>) -> bool {
+        (0..self.width as isize).contains(&pos.x) && (0..self.height as isize).contains(&pos.y)
+    }
+
+    fn index_of(&self, pos: Vec2<isize>) -> Option<usize> {
+        self.contains(pos)
+            .then(|| pos.y as usize * self.width + pos.x as usize)
+    }
+
+    fn pos_of(&self, idx: usize) -> Vec2<isize> {
+        Vec2 {
+            x: (idx % self.width) as isize,
+            y: (idx / self.width) as isize,
+        }
+    }
+
+    /// The cell at the given position, `None` if it's outside of the grid
+    pub fn get(&self, pos: Vec2<isize>) -> Option<&T> {
+        self.index_of(pos).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, pos: Vec2<isize>) -> Option<&mut T> {
+        self.index_of(pos).map(|idx| &mut self.cells[idx])
+    }
+
+    /// The cell at the given position of the grid repeated infinitely in all directions
+    pub fn get_wrapped(&self, pos: Vec2<isize>) -> &T {
+        &self[self.wrap(pos)]
+    }
+
+    /// The position inside the grid corresponding to the given one, when the grid
+    /// is repeated infinitely in all directions
+    pub fn wrap(&self, pos: Vec2<isize>) -> Vec2<isize> {
+        let size = self.size();
+        Vec2 {
+            x: pos.x.rem_euclid(size.x),
+            y: pos.y.rem_euclid(size.y),
+        }
+    }
+
+    /// Which copy of the grid the given position falls into, when the grid
+    /// is repeated infinitely in all directions. `(0, 0)` is the grid itself.
+    pub fn tile(&self, pos: Vec2<isize>) -> Vec2<isize> {
+        let size = self.size();
+        Vec2 {
+            x: pos.x.div_euclid(size.x),
+            y: pos.y.div_euclid(size.y),
+        }
+    }
+
+    /// All positions, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Vec2<isize>> {
+        let width = self.width as isize;
+        (0..self.height as isize).flat_map(move |y| (0..width).map(move |x| Vec2 { x, y }))
+    }
+
+    /// All cells with their positions, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2<isize>, &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(idx, cell)| (self.pos_of(idx), cell))
+    }
+
+    /// All cells, row by row
+    pub fn values(&self) -> impl Iterator<Item = &T> + '_ {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> + '_ {
+        self.cells.iter_mut()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "Row {} is outside of the grid", y);
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        assert!(x < self.width, "Column {} is outside of the grid", x);
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    /// Positions of the horizontal and vertical neighbours inside the grid
+    pub fn neighbors4(&self, pos: Vec2<isize>) -> impl Iterator<Item = Vec2<isize>> + '_ {
+        orthogonal_directions()
+            .map(move |dir| pos + dir)
+            .filter(|p| self.contains(*p))
+    }
+
+    /// Positions of the neighbours inside the grid, diagonal ones included
+    pub fn neighbors8(&self, pos: Vec2<isize>) -> impl Iterator<Item = Vec2<isize>> + '_ {
+        all_directions()
+            .map(move |dir| pos + dir)
+            .filter(|p| self.contains(*p))
+    }
+
+    /// A grid of the same size with every cell transformed
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T> Index<Vec2<isize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Vec2<isize>) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Vec2<isize>> for Grid<T> {
+    fn index_mut(&mut self, pos: Vec2<isize>) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", pos))
+    }
+}
+
+/// Parse a character map, one line per row
+impl<'a, T: From<char>> FromIterator<&'a str> for Grid<T> {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        Self::from_rows(iter.into_iter().map(|line| line.chars().map(T::from)))
+    }
+}
+
+/// Render the grid one row per line, with cells next to each other
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const MAP: [&str; 3] = ["#..", "..#", ".##"];
+
+    fn grid() -> Grid<char> {
+        Grid::from_iter(MAP)
+    }
+
+    #[test]
+    fn test_parse_and_render() {
+        let grid = grid();
+        assert_eq!((3, 3), (grid.width(), grid.height()));
+        assert_eq!("#..\n..#\n.##\n", grid.to_string());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ragged_rows() {
+        Grid::<char>::from_iter(["#..", "#."]);
+    }
+
+    #[test]
+    fn test_get() {
+        let grid = grid();
+        assert_eq!(Some(&'#'), grid.get(Vec2 { x: 2, y: 1 }));
+        assert_eq!(Some(&'.'), grid.get(Vec2 { x: 0, y: 2 }));
+        assert_eq!(None, grid.get(Vec2 { x: 3, y: 0 }));
+        assert_eq!(None, grid.get(Vec2 { x: 0, y: -1 }));
+        assert_eq!('#', grid[Vec2 { x: 1, y: 2 }]);
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let grid = grid();
+        assert_eq!(&'#', grid.get_wrapped(Vec2 { x: 5, y: 4 }));
+        assert_eq!(&'#', grid.get_wrapped(Vec2 { x: -2, y: -1 }));
+        assert_eq!(Vec2 { x: 1, y: 0 }, grid.tile(Vec2 { x: 5, y: 2 }));
+        assert_eq!(Vec2 { x: -1, y: -1 }, grid.tile(Vec2 { x: -2, y: -1 }));
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = grid();
+        let corner = Vec2 { x: 0, y: 0 };
+        assert_eq!(2, grid.neighbors4(corner).count());
+        assert_eq!(3, grid.neighbors8(corner).count());
+        let center = Vec2 { x: 1, y: 1 };
+        assert_eq!(4, grid.neighbors4(center).count());
+        assert_eq!(8, grid.neighbors8(center).count());
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = grid();
+        assert_eq!(vec!['.', '.', '#'], grid.row(1));
+        assert_eq!(3, grid.rows().count());
+        assert_eq!(
+            vec!['.', '#', '#'],
+            grid.column(2).copied().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![1, 1, 2],
+            grid.columns()
+                .map(|c| c.filter(|c| **c == '#').count())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Some(Vec2 { x: 1, y: 2 }),
+            grid.iter()
+                .filter(|(_, c)| **c == '#')
+                .map(|(p, _)| p)
+                .nth(2)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_column_outside() {
+        grid().column(3).count();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_row_outside() {
+        grid().row(3);
+    }
+
+    #[test]
+    fn test_empty_rows() {
+        let grid = Grid::<char>::from_iter(["", ""]);
+        assert_eq!((0, 2), (grid.width(), grid.height()));
+        assert_eq!(2, grid.rows().count());
+        assert!(grid.rows().all(|row| row.is_empty()));
+    }
+}
@@ -0,0 +1,109 @@
+use std::borrow::Cow;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Environment variable pointing to a directory with puzzle inputs
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where to take the puzzle input from
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    /// The input embedded in the binary at compile time
+    Embedded,
+    /// A file under the given path
+    File(PathBuf),
+    /// The standard input
+    Stdin,
+    /// A directory with inputs laid out as `<dir>/<year>/day<day>.txt`.
+    /// Falls back to the embedded input if there is no file for the day.
+    Dir(PathBuf),
+}
+
+/// Path of the input for the given day inside an input directory
+pub fn path_in(dir: &Path, year: u16, day: u8) -> PathBuf {
+    dir.join(year.to_string()).join(format!("day{}.txt", day))
+}
+
+impl InputSource {
+    /// Interpret a command line argument:
+    /// `-` stands for the standard input, anything else is a file path.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(path.into()),
+        }
+    }
+
+    /// The directory set in [`INPUT_DIR_VAR`] or the embedded input if it's not set.
+    pub fn from_env() -> Self {
+        match std::env::var_os(INPUT_DIR_VAR) {
+            Some(dir) if !dir.is_empty() => InputSource::Dir(dir.into()),
+            _ => InputSource::Embedded,
+        }
+    }
+
+    /// Use the command line argument if given, the environment otherwise.
+    pub fn from_arg_or_env(arg: Option<&str>) -> Self {
+        arg.map_or_else(Self::from_env, Self::from_arg)
+    }
+
+    /// Read the input for the given day
+    pub fn load(
+        &self,
+        year: u16,
+        day: u8,
+        embedded: &'static str,
+    ) -> io::Result<Cow<'static, str>> {
+        match self {
+            InputSource::Embedded => Ok(Cow::Borrowed(embedded)),
+            InputSource::File(path) => std::fs::read_to_string(path).map(Cow::Owned),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(Cow::Owned(input))
+            }
+            InputSource::Dir(dir) => match std::fs::read_to_string(path_in(dir, year, day)) {
+                Ok(input) => Ok(Cow::Owned(input)),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Cow::Borrowed(embedded)),
+                Err(e) => Err(e),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(InputSource::Stdin, InputSource::from_arg("-"));
+        assert_eq!(
+            InputSource::File("inputs/day1.txt".into()),
+            InputSource::from_arg("inputs/day1.txt")
+        );
+    }
+
+    #[test]
+    fn test_load_from_dir() {
+        let dir = std::env::temp_dir().join(format!("aoc-input-test-{}", std::process::id()));
+        let path = path_in(&dir, 2021, 1);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "from dir").unwrap();
+
+        let source = InputSource::Dir(dir.clone());
+        assert_eq!("from dir", source.load(2021, 1, "embedded").unwrap());
+        // No file for this day, so the embedded input is used
+        assert_eq!("embedded", source.load(2021, 2, "embedded").unwrap());
+
+        assert_eq!(
+            "from dir",
+            InputSource::File(path).load(2021, 2, "").unwrap()
+        );
+        assert!(InputSource::File(dir.join("missing"))
+            .load(2021, 1, "")
+            .is_err());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,26 +1,24 @@
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
-pub struct Vec2<T> {
-    pub x: T,
-    pub y: T,
-}
+pub mod cycle;
+pub mod fetch;
+pub mod grid;
+pub mod input;
+pub mod search;
+pub mod solution;
+pub mod sparse_grid;
+pub mod vec;
 
-impl<T> std::ops::Add<Vec2<T>> for Vec2<T>
-where
-    T: std::ops::Add<Output = T>,
-{
-    type Output = Vec2<T>;
-    fn add(self, rhs: Vec2<T>) -> Vec2<T> {
-        Vec2 {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-        }
-    }
-}
+pub use grid::Grid;
+pub use sparse_grid::SparseGrid;
+pub use vec::{Vec2, Vec3};
 
 /// Iterate over vectors of all 8 directions (north, north-east, east and so on)
 pub fn all_directions() -> impl Iterator<Item = Vec2<isize>> {
     (-1..=1)
-        .map(|x| (-1..=1).map(move |y| Vec2 { x, y }))
-        .flatten()
+        .flat_map(|x| (-1..=1).map(move |y| Vec2 { x, y }))
         .filter(|v| *v != Vec2 { x: 0, y: 0 })
 }
+
+/// Iterate over vectors of the 4 horizontal and vertical directions
+pub fn orthogonal_directions() -> impl Iterator<Item = Vec2<isize>> {
+    all_directions().filter(|v| v.x * v.y == 0)
+}
@@ -0,0 +1,285 @@
+//! Searches over state spaces given by a successor function.
+//!
+//! States are anything hashable, the successors are computed on the fly,
+//! so the whole state space doesn't need to be built upfront.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Follow the parents from the state with the given index back to the start
+fn reconstruct_path<S: Clone>(states: &[(S, usize)], mut idx: usize) -> Vec<S> {
+    let mut path = vec![];
+    loop {
+        let (state, parent) = &states[idx];
+        path.push(state.clone());
+        if *parent == idx {
+            break;
+        }
+        idx = *parent;
+    }
+    path.reverse();
+    path
+}
+
+/// Breadth-first search for the path with the fewest steps from `start` to a goal.
+/// The path includes both the start and the goal.
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Vec<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    // Every state along with the index of its parent, the start is its own parent
+    let mut states = vec![(start.clone(), 0)];
+    let mut seen = HashMap::from([(start, 0)]);
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(idx) = queue.pop_front() {
+        if is_goal(&states[idx].0) {
+            return Some(reconstruct_path(&states, idx));
+        }
+        for next in successors(&states[idx].0) {
+            if let Entry::Vacant(entry) = seen.entry(next.clone()) {
+                entry.insert(states.len());
+                queue.push_back(states.len());
+                states.push((next, idx));
+            }
+        }
+    }
+    None
+}
+
+/// A* search for the cheapest path from `start` to a goal.
+///
+/// The successors come with the cost of getting to them. The heuristic must never
+/// overestimate the cost of getting from a state to a goal, otherwise the found
+/// path might not be the cheapest one.
+/// Returns the path, including both the start and the goal, and its cost.
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    // Every state along with the index of its parent, the start is its own parent
+    let mut states = vec![(start.clone(), 0)];
+    // The index of every state and the lowest cost of getting to it so far
+    let mut best = HashMap::from([(start.clone(), (0, C::default()))]);
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+
+    while let Some(Reverse((_, cost, idx))) = queue.pop() {
+        let state = &states[idx].0;
+        if best[state].1 < cost {
+            // Got there cheaper in the meantime
+            continue;
+        }
+        if is_goal(state) {
+            return Some((reconstruct_path(&states, idx), cost));
+        }
+
+        for (next, step_cost) in successors(state) {
+            let next_cost = cost + step_cost;
+            let next_idx = match best.entry(next.clone()) {
+                Entry::Occupied(mut entry) => {
+                    if entry.get().1 <= next_cost {
+                        continue;
+                    }
+                    let next_idx = entry.get().0;
+                    entry.insert((next_idx, next_cost));
+                    states[next_idx].1 = idx;
+                    next_idx
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert((states.len(), next_cost));
+                    states.push((next.clone(), idx));
+                    states.len() - 1
+                }
+            };
+            queue.push(Reverse((next_cost + heuristic(&next), next_cost, next_idx)));
+        }
+    }
+    None
+}
+
+/// Dijkstra's search for the cheapest path from `start` to a goal,
+/// i.e. [`astar`] without a heuristic.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// The lowest cost of getting from `start` to every reachable state
+pub fn dijkstra_all<S, C, I>(start: S, mut successors: impl FnMut(&S) -> I) -> HashMap<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut states = vec![start.clone()];
+    let mut best = HashMap::from([(start, (0, C::default()))]);
+    let mut queue = BinaryHeap::from([Reverse((C::default(), 0))]);
+
+    while let Some(Reverse((cost, idx))) = queue.pop() {
+        let state = &states[idx];
+        if best[state].1 < cost {
+            continue;
+        }
+        for (next, step_cost) in successors(state) {
+            let next_cost = cost + step_cost;
+            match best.entry(next) {
+                Entry::Occupied(mut entry) => {
+                    if next_cost < entry.get().1 {
+                        entry.get_mut().1 = next_cost;
+                        queue.push(Reverse((next_cost, entry.get().0)));
+                    }
+                }
+                Entry::Vacant(entry) => {
+                    states.push(entry.key().clone());
+                    entry.insert((states.len() - 1, next_cost));
+                    queue.push(Reverse((next_cost, states.len() - 1)));
+                }
+            }
+        }
+    }
+    best.into_iter()
+        .map(|(state, (_, cost))| (state, cost))
+        .collect()
+}
+
+/// Count the distinct paths from `start` to the goals. Goals are not expanded further.
+///
+/// The number of paths from every state is memoized, so the state space must not
+/// have cycles (e.g. the states can remember what has been visited already).
+pub fn count_paths<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> usize
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    fn count<S, I>(
+        state: S,
+        successors: &mut impl FnMut(&S) -> I,
+        is_goal: &mut impl FnMut(&S) -> bool,
+        known: &mut HashMap<S, usize>,
+    ) -> usize
+    where
+        S: Clone + Eq + Hash,
+        I: IntoIterator<Item = S>,
+    {
+        if is_goal(&state) {
+            return 1;
+        }
+        if let Some(paths) = known.get(&state) {
+            return *paths;
+        }
+        let paths = successors(&state)
+            .into_iter()
+            .map(|next| count(next, successors, is_goal, known))
+            .sum();
+        known.insert(state, paths);
+        paths
+    }
+
+    count(start, &mut successors, &mut is_goal, &mut HashMap::new())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Grid, Vec2};
+
+    const MAZE: [&str; 5] = ["S.#...", ".##.#.", "...#..", ".#...#", "...#.E"];
+
+    fn maze() -> (Grid<char>, Vec2<isize>, Vec2<isize>) {
+        let maze = Grid::from_iter(MAZE);
+        let find = |c| maze.iter().find(|(_, cell)| **cell == c).unwrap().0;
+        let (start, end) = (find('S'), find('E'));
+        (maze, start, end)
+    }
+
+    #[test]
+    fn test_bfs() {
+        let (maze, start, end) = maze();
+        let path = bfs(
+            start,
+            |pos| maze.neighbors4(*pos).filter(|p| maze[*p] != '#'),
+            |pos| *pos == end,
+        )
+        .unwrap();
+        assert_eq!(10, path.len());
+        assert_eq!((Some(&start), Some(&end)), (path.first(), path.last()));
+        assert!(path.windows(2).all(|w| w[0].manhattan_distance(w[1]) == 1));
+
+        assert_eq!(None, bfs(start, |_| vec![], |pos| *pos == end));
+    }
+
+    #[test]
+    fn test_dijkstra_and_astar() {
+        // Walking over '.' costs 1, wading through '#' costs 10
+        let (maze, start, end) = maze();
+        let successors = |pos: &Vec2<isize>| {
+            maze.neighbors4(*pos)
+                .map(|p| (p, if maze[p] == '#' { 10 } else { 1 }))
+                .collect::<Vec<_>>()
+        };
+
+        let (path, cost) = dijkstra(start, successors, |pos| *pos == end).unwrap();
+        assert_eq!(9, cost);
+        assert_eq!(10, path.len());
+
+        let (path, cost) = astar(
+            start,
+            successors,
+            |pos| pos.manhattan_distance(end),
+            |pos| *pos == end,
+        )
+        .unwrap();
+        assert_eq!(9, cost);
+        assert_eq!(Some(&end), path.last());
+
+        let costs = dijkstra_all(start, successors);
+        assert_eq!(maze.width() * maze.height(), costs.len());
+        assert_eq!(9, costs[&end]);
+        assert_eq!(0, costs[&start]);
+    }
+
+    #[test]
+    fn test_count_paths() {
+        // Paths to the bottom right corner going only right and down
+        let (maze, start, end) = maze();
+        let open = maze.map(|_| '.');
+        let paths = count_paths(
+            start,
+            |pos| {
+                [*pos + Vec2::new(1, 0), *pos + Vec2::new(0, 1)]
+                    .into_iter()
+                    .filter(|p| open.contains(*p))
+            },
+            |pos| *pos == end,
+        );
+        // Choose which 4 of the 9 steps go down
+        assert_eq!(126, paths);
+    }
+}
@@ -0,0 +1,128 @@
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+use crate::input::InputSource;
+
+/// One of the two parts every puzzle consists of
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// A solution for a single day of the Advent of Code.
+///
+/// The puzzle input is parsed once and then handed to both parts.
+/// A part that has not been solved yet returns `None`.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    /// The puzzle input embedded in the binary
+    const INPUT: &'static str;
+
+    type Input<'a>;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Self::Input<'_>;
+
+    fn part1(input: &Self::Input<'_>) -> Option<Self::Answer1>;
+
+    fn part2(_input: &Self::Input<'_>) -> Option<Self::Answer2> {
+        None
+    }
+}
+
+/// Parsed puzzle input, with the answers type-erased into strings
+pub trait Parsed {
+    fn solve(&self, part: Part) -> Option<String>;
+}
+
+/// Object-safe counterpart of [`Solution`], so that solutions
+/// of different days can be kept together in a registry.
+pub trait Solver: Send + Sync {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    fn input(&self) -> &'static str;
+    fn parse<'a>(&self, input: &'a str) -> Box<dyn Parsed + 'a>;
+
+    /// Parse the input and solve the given part
+    fn solve(&self, input: &str, part: Part) -> Option<String> {
+        self.parse(input).solve(part)
+    }
+}
+
+struct Erased<S>(PhantomData<fn() -> S>);
+
+struct ParsedInput<'a, S: Solution>(S::Input<'a>);
+
+impl<S: Solution> Parsed for ParsedInput<'_, S> {
+    fn solve(&self, part: Part) -> Option<String> {
+        match part {
+            Part::One => S::part1(&self.0).map(|answer| answer.to_string()),
+            Part::Two => S::part2(&self.0).map(|answer| answer.to_string()),
+        }
+    }
+}
+
+impl<S: Solution + 'static> Solver for Erased<S> {
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn input(&self) -> &'static str {
+        S::INPUT
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Box<dyn Parsed + 'a> {
+        Box::new(ParsedInput::<S>(S::parse(input)))
+    }
+}
+
+/// Type-erase a solution
+pub fn solver<S: Solution + 'static>() -> Box<dyn Solver> {
+    Box::new(Erased::<S>(PhantomData))
+}
+
+/// Solve both parts and print the answers.
+/// Meant to be called from every day's `main`.
+///
+/// The first command line argument, if given, is the input file (`-` for stdin).
+/// Otherwise the input is taken from [`crate::input::INPUT_DIR_VAR`] directory,
+/// falling back to the embedded one.
+pub fn run<S: Solution>() {
+    let arg = std::env::args().nth(1);
+    let source = InputSource::from_arg_or_env(arg.as_deref());
+    let input = match source.load(S::YEAR, S::DAY, S::INPUT) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Failed to read the input: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let input = S::parse(&input);
+    for (part, answer) in [
+        (Part::One, S::part1(&input).map(|a| a.to_string())),
+        (Part::Two, S::part2(&input).map(|a| a.to_string())),
+    ] {
+        match answer {
+            Some(answer) => println!("PART{}: {}", part, answer),
+            None => println!("PART{}: not solved", part),
+        }
+    }
+}
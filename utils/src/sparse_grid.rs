@@ -0,0 +1,301 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use crate::{Grid, Vec2};
+
+/// One of the axes of a 2D grid
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Axis {
+    X,
+    Y,
+}
+
+/// An infinite 2D grid where all cells hold the background value,
+/// except for the ones stored explicitly.
+///
+/// Keeps track of the bounding box of the stored cells.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Vec2<isize>, T>,
+    background: T,
+    /// Inclusive bounds of the stored cells, `None` if there are none
+    bounds: Option<(Vec2<isize>, Vec2<isize>)>,
+}
+
+impl<T: Default> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new(background: T) -> Self {
+        Self {
+            cells: HashMap::new(),
+            background,
+            bounds: None,
+        }
+    }
+
+    pub fn background(&self) -> &T {
+        &self.background
+    }
+
+    /// The cell at the given position
+    pub fn get(&self, pos: Vec2<isize>) -> &T {
+        self.cells.get(&pos).unwrap_or(&self.background)
+    }
+
+    /// Number of cells that are not the background
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// All cells that are not the background, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2<isize>, &T)> + '_ {
+        self.cells.iter().map(|(pos, cell)| (*pos, cell))
+    }
+
+    /// The smallest and the largest corner of the box containing
+    /// all cells that are not the background
+    pub fn bounds(&self) -> Option<(Vec2<isize>, Vec2<isize>)> {
+        self.bounds
+    }
+
+    fn recompute_bounds(&mut self) {
+        self.bounds = None;
+        for pos in self.cells.keys() {
+            self.bounds = Some(extend(self.bounds, *pos));
+        }
+    }
+
+    /// Only the cells inside of the given box (inclusive)
+    pub fn crop(&self, min: Vec2<isize>, max: Vec2<isize>) -> Self
+    where
+        T: Clone,
+    {
+        let mut grid = Self::new(self.background.clone());
+        for (pos, cell) in self.iter() {
+            if (min.x..=max.x).contains(&pos.x) && (min.y..=max.y).contains(&pos.y) {
+                grid.insert(pos, cell.clone());
+            }
+        }
+        grid
+    }
+
+    /// Store a cell without checking if it's the background
+    fn insert(&mut self, pos: Vec2<isize>, value: T) {
+        self.cells.insert(pos, value);
+        self.bounds = Some(extend(self.bounds, pos));
+    }
+
+    /// Mirror the grid along the line `axis = at`
+    pub fn flip(&self, axis: Axis, at: isize) -> Self
+    where
+        T: Clone,
+    {
+        let mut grid = Self::new(self.background.clone());
+        for (pos, cell) in self.iter() {
+            grid.insert(mirror(pos, axis, at), cell.clone());
+        }
+        grid
+    }
+
+    /// Fold the part of the grid past the line `axis = at` onto the part before it,
+    /// like a sheet of paper. Cells on the line stay where they are. Where two cells
+    /// end up on top of each other, the one that was not folded is kept.
+    pub fn fold(&self, axis: Axis, at: isize) -> Self
+    where
+        T: Clone,
+    {
+        let coord = |pos: Vec2<isize>| match axis {
+            Axis::X => pos.x,
+            Axis::Y => pos.y,
+        };
+        let mut grid = Self::new(self.background.clone());
+        for (pos, cell) in self.iter().filter(|(pos, _)| coord(*pos) <= at) {
+            grid.insert(pos, cell.clone());
+        }
+        for (pos, cell) in self.iter().filter(|(pos, _)| coord(*pos) > at) {
+            let pos = mirror(pos, axis, at);
+            if !grid.cells.contains_key(&pos) {
+                grid.insert(pos, cell.clone());
+            }
+        }
+        grid
+    }
+
+    /// Render the bounding box, one row per line
+    pub fn render(&self, mut f: impl FnMut(&T) -> char) -> String {
+        let Some((min, max)) = self.bounds else {
+            return String::new();
+        };
+        (min.y..=max.y)
+            .map(|y| {
+                (min.x..=max.x)
+                    .map(|x| f(self.get(Vec2 { x, y })))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// A dense copy of the bounding box. Its top-left corner is the smallest bound.
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let Some((min, max)) = self.bounds else {
+            return Grid::from_rows(std::iter::empty::<Vec<T>>());
+        };
+        let size = Vec2 {
+            x: max.x - min.x + 1,
+            y: max.y - min.y + 1,
+        };
+        Grid::from_fn(size.x as usize, size.y as usize, |pos| {
+            self.get(pos + min).clone()
+        })
+    }
+}
+
+impl<T: PartialEq> SparseGrid<T> {
+    /// Set the cell at the given position.
+    /// Setting a cell to the background value removes it.
+    pub fn set(&mut self, pos: Vec2<isize>, value: T) {
+        if value != self.background {
+            self.insert(pos, value);
+        } else if self.cells.remove(&pos).is_some() {
+            let on_edge = self.bounds.is_some_and(|(min, max)| {
+                pos.x == min.x || pos.y == min.y || pos.x == max.x || pos.y == max.y
+            });
+            if on_edge {
+                self.recompute_bounds();
+            }
+        }
+    }
+}
+
+fn extend(
+    bounds: Option<(Vec2<isize>, Vec2<isize>)>,
+    pos: Vec2<isize>,
+) -> (Vec2<isize>, Vec2<isize>) {
+    match bounds {
+        None => (pos, pos),
+        Some((min, max)) => (
+            Vec2 {
+                x: min.x.min(pos.x),
+                y: min.y.min(pos.y),
+            },
+            Vec2 {
+                x: max.x.max(pos.x),
+                y: max.y.max(pos.y),
+            },
+        ),
+    }
+}
+
+fn mirror(pos: Vec2<isize>, axis: Axis, at: isize) -> Vec2<isize> {
+    match axis {
+        Axis::X => Vec2 {
+            x: 2 * at - pos.x,
+            y: pos.y,
+        },
+        Axis::Y => Vec2 {
+            x: pos.x,
+            y: 2 * at - pos.y,
+        },
+    }
+}
+
+/// Collect cells onto the default background
+impl<T: PartialEq + Default> FromIterator<(Vec2<isize>, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Vec2<isize>, T)>>(iter: I) -> Self {
+        let mut grid = Self::default();
+        for (pos, value) in iter {
+            grid.set(pos, value);
+        }
+        grid
+    }
+}
+
+/// Render the bounding box, one row per line
+impl<T: Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some((min, max)) = self.bounds else {
+            return Ok(());
+        };
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                write!(f, "{}", self.get(Vec2 { x, y }))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn points(points: &[(isize, isize)]) -> SparseGrid<bool> {
+        points.iter().map(|&(x, y)| (Vec2 { x, y }, true)).collect()
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = points(&[(1, 2), (-3, 4), (0, 0)]);
+        assert_eq!(3, grid.len());
+        assert_eq!(
+            Some((Vec2 { x: -3, y: 0 }, Vec2 { x: 1, y: 4 })),
+            grid.bounds()
+        );
+        assert!(*grid.get(Vec2 { x: 1, y: 2 }));
+        assert!(!*grid.get(Vec2 { x: 100, y: 2 }));
+
+        grid.set(Vec2 { x: -3, y: 4 }, false);
+        assert_eq!(
+            Some((Vec2 { x: 0, y: 0 }, Vec2 { x: 1, y: 2 })),
+            grid.bounds()
+        );
+        grid.set(Vec2 { x: 0, y: 0 }, false);
+        grid.set(Vec2 { x: 1, y: 2 }, false);
+        assert!(grid.is_empty());
+        assert_eq!(None, grid.bounds());
+    }
+
+    #[test]
+    fn test_render() {
+        let grid = points(&[(0, 0), (2, 1)]);
+        assert_eq!("#..\n..#", grid.render(|p| if *p { '#' } else { '.' }));
+
+        let mut digits = SparseGrid::new(0);
+        digits.set(Vec2 { x: 5, y: 5 }, 1);
+        digits.set(Vec2 { x: 6, y: 6 }, 2);
+        assert_eq!("10\n02\n", digits.to_string());
+        assert_eq!(digits.to_string(), digits.to_grid().to_string());
+        assert_eq!(
+            "1",
+            digits
+                .crop(Vec2 { x: 0, y: 0 }, Vec2 { x: 5, y: 5 })
+                .to_string()
+                .trim()
+        );
+    }
+
+    #[test]
+    fn test_flip_and_fold() {
+        let grid = points(&[(0, 0), (4, 1), (2, 1)]);
+        assert_eq!(points(&[(0, 0), (-4, 1), (-2, 1)]), grid.flip(Axis::X, 0));
+        assert_eq!(points(&[(0, 4), (4, 3), (2, 3)]), grid.flip(Axis::Y, 2));
+
+        // (2, 1) lies on the fold line and stays, (4, 1) lands on (0, 1)
+        assert_eq!(points(&[(0, 0), (2, 1), (0, 1)]), grid.fold(Axis::X, 2));
+        // Folding up onto the negative side, past (0, 0) on the line
+        assert_eq!(points(&[(0, 0), (4, -1), (2, -1)]), grid.fold(Axis::Y, 0));
+    }
+}
@@ -0,0 +1,337 @@
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+/// Component-wise arithmetic, multiplication by a scalar
+/// and conversions between vectors of different integer types
+macro_rules! impl_ops {
+    ($vec:ident { $($c:ident),+ }) => {
+        impl<T> $vec<T> {
+            pub const fn new($($c: T),+) -> Self {
+                Self { $($c),+ }
+            }
+
+            /// Convert to a vector of a type that can hold all values of `T`
+            pub fn cast<U: From<T>>(self) -> $vec<U> {
+                $vec { $($c: U::from(self.$c)),+ }
+            }
+
+            /// Convert to a vector of a type that might not hold all values of `T`
+            pub fn try_cast<U: TryFrom<T>>(self) -> Result<$vec<U>, U::Error> {
+                Ok($vec { $($c: U::try_from(self.$c)?),+ })
+            }
+        }
+
+        impl<T: Add<Output = T>> Add<$vec<T>> for $vec<T> {
+            type Output = $vec<T>;
+            fn add(self, rhs: $vec<T>) -> $vec<T> {
+                $vec { $($c: self.$c + rhs.$c),+ }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub<$vec<T>> for $vec<T> {
+            type Output = $vec<T>;
+            fn sub(self, rhs: $vec<T>) -> $vec<T> {
+                $vec { $($c: self.$c - rhs.$c),+ }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $vec<T> {
+            type Output = $vec<T>;
+            fn neg(self) -> $vec<T> {
+                $vec { $($c: -self.$c),+ }
+            }
+        }
+
+        impl<T: Mul<Output = T> + Copy> Mul<T> for $vec<T> {
+            type Output = $vec<T>;
+            fn mul(self, rhs: T) -> $vec<T> {
+                $vec { $($c: self.$c * rhs),+ }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign<$vec<T>> for $vec<T> {
+            fn add_assign(&mut self, rhs: $vec<T>) {
+                $(self.$c += rhs.$c;)+
+            }
+        }
+
+        impl<T: SubAssign> SubAssign<$vec<T>> for $vec<T> {
+            fn sub_assign(&mut self, rhs: $vec<T>) {
+                $(self.$c -= rhs.$c;)+
+            }
+        }
+
+        impl<T: MulAssign + Copy> MulAssign<T> for $vec<T> {
+            fn mul_assign(&mut self, rhs: T) {
+                $(self.$c *= rhs;)+
+            }
+        }
+    };
+}
+
+impl_ops!(Vec2 { x, y });
+impl_ops!(Vec3 { x, y, z });
+
+/// Signed integer types, for the operations that need a sign or constants
+pub trait Signed:
+    Copy + Ord + Add<Output = Self> + Sub<Output = Self> + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const MINUS_ONE: Self;
+
+    fn abs(self) -> Self;
+}
+
+macro_rules! impl_signed {
+    ($($t:ty)+) => {
+        $(
+            impl Signed for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                const MINUS_ONE: Self = -1;
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+            }
+        )+
+    };
+}
+
+impl_signed!(i8 i16 i32 i64 i128 isize);
+
+/// Unit vectors of the cardinal directions, with `y` growing to the north.
+/// Note that in a [`crate::Grid`] `y` grows downwards, so there `NORTH` points down.
+impl<T: Signed> Vec2<T> {
+    pub const NORTH: Self = Self {
+        x: T::ZERO,
+        y: T::ONE,
+    };
+    pub const EAST: Self = Self {
+        x: T::ONE,
+        y: T::ZERO,
+    };
+    pub const SOUTH: Self = Self {
+        x: T::ZERO,
+        y: T::MINUS_ONE,
+    };
+    pub const WEST: Self = Self {
+        x: T::MINUS_ONE,
+        y: T::ZERO,
+    };
+
+    /// Sum of the absolute differences of the coordinates
+    pub fn manhattan_distance(self, other: Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// The largest absolute difference of the coordinates,
+    /// i.e. the number of king moves on a chessboard
+    pub fn chebyshev_distance(self, other: Self) -> T {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+}
+
+impl<T: Signed> Vec3<T> {
+    /// Sum of the absolute differences of the coordinates
+    pub fn manhattan_distance(self, other: Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    /// The largest absolute difference of the coordinates
+    pub fn chebyshev_distance(self, other: Self) -> T {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+}
+
+impl<T: Neg<Output = T>> Vec2<T> {
+    /// Rotate by 90 degrees counter-clockwise (e.g. from east to north)
+    pub fn rotate_left(self) -> Self {
+        Self {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+
+    /// Rotate by 90 degrees clockwise (e.g. from north to east)
+    pub fn rotate_right(self) -> Self {
+        Self {
+            x: self.y,
+            y: -self.x,
+        }
+    }
+
+    /// Rotate counter-clockwise by the given angle in degrees,
+    /// clockwise if it's negative. Panics if it's not a multiple of 90.
+    pub fn rotate(self, degrees: i32) -> Self {
+        match degrees.rem_euclid(360) {
+            0 => self,
+            90 => self.rotate_left(),
+            180 => -self,
+            270 => self.rotate_right(),
+            _ => panic!("The angle must be a multiple of 90 degrees."),
+        }
+    }
+}
+
+impl<T: Neg<Output = T> + Copy> Vec3<T> {
+    /// Number of axis-aligned rotations, see [`Vec3::rotate`]
+    pub const ROTATIONS: usize = 24;
+
+    /// Apply one of the 24 axis-aligned rotations (facing any of the 6 directions
+    /// and turned in any of the 4 ways). Rotation `0` is the identity.
+    pub fn rotate(self, rotation: usize) -> Self {
+        let Vec3 { x, y, z } = self;
+        let (x, y, z) = match rotation {
+            0 => (x, y, z),
+            1 => (x, -z, y),
+            2 => (x, -y, -z),
+            3 => (x, z, -y),
+            4 => (-x, -y, z),
+            5 => (-x, -z, -y),
+            6 => (-x, y, -z),
+            7 => (-x, z, y),
+            8 => (y, x, -z),
+            9 => (y, -x, z),
+            10 => (y, z, x),
+            11 => (y, -z, -x),
+            12 => (-y, x, z),
+            13 => (-y, -x, -z),
+            14 => (-y, -z, x),
+            15 => (-y, z, -x),
+            16 => (z, x, y),
+            17 => (z, -x, -y),
+            18 => (z, -y, x),
+            19 => (z, y, -x),
+            20 => (-z, x, -y),
+            21 => (-z, -x, y),
+            22 => (-z, y, x),
+            23 => (-z, -y, -x),
+            _ => panic!("There are only {} rotations", Self::ROTATIONS),
+        };
+        Vec3 { x, y, z }
+    }
+
+    /// The vector in all 24 orientations
+    pub fn rotations(self) -> impl Iterator<Item = Self> {
+        (0..Self::ROTATIONS).map(move |rotation| self.rotate(rotation))
+    }
+}
+
+impl<T> From<(T, T)> for Vec2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<Vec2<T>> for (T, T) {
+    fn from(v: Vec2<T>) -> Self {
+        (v.x, v.y)
+    }
+}
+
+impl<T> From<(T, T, T)> for Vec3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T> From<Vec3<T>> for (T, T, T) {
+    fn from(v: Vec3<T>) -> Self {
+        (v.x, v.y, v.z)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let mut v = Vec2::new(1, -2);
+        assert_eq!(Vec2::new(4, 2), v + Vec2::new(3, 4));
+        assert_eq!(Vec2::new(-2, -6), v - Vec2::new(3, 4));
+        assert_eq!(Vec2::new(-1, 2), -v);
+        assert_eq!(Vec2::new(3, -6), v * 3);
+        v += Vec2::new(1, 1);
+        v *= 2;
+        v -= Vec2::new(0, 1);
+        assert_eq!(Vec2::new(4, -3), v);
+        assert_eq!(
+            Vec3::new(2, 4, 6),
+            (Vec3::new(1, 2, 3) + Vec3::new(0, 0, 0)) * 2
+        );
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Vec2::new(1, -2);
+        let b = Vec2::new(-3, 5);
+        assert_eq!(11, a.manhattan_distance(b));
+        assert_eq!(7, a.chebyshev_distance(b));
+        let a = Vec3::new(1105, -1205, 1229);
+        let b = Vec3::new(-92, -2380, -20);
+        assert_eq!(3621, a.manhattan_distance(b));
+        assert_eq!(1249, a.chebyshev_distance(b));
+    }
+
+    #[test]
+    fn test_rotate_vec2() {
+        let east = Vec2::<isize>::EAST;
+        assert_eq!(Vec2::NORTH, east.rotate_left());
+        assert_eq!(Vec2::SOUTH, east.rotate_right());
+        assert_eq!(Vec2::NORTH, east.rotate(90));
+        assert_eq!(Vec2::SOUTH, east.rotate(-90));
+        assert_eq!(Vec2::WEST, east.rotate(180));
+        assert_eq!(Vec2::WEST, east.rotate(-180));
+        assert_eq!(Vec2::SOUTH, east.rotate(630));
+        assert_eq!(Vec2::new(-1, -2), Vec2::new(2, -1).rotate(-90));
+    }
+
+    #[test]
+    fn test_rotations_vec3() {
+        let v = Vec3::new(1, 2, 3);
+        assert_eq!(v, v.rotate(0));
+        assert_eq!(24, v.rotations().collect::<HashSet<_>>().len());
+        // Rotations preserve the orientation of the axes (no mirror images)
+        for rotation in 0..Vec3::<i32>::ROTATIONS {
+            let x = Vec3::new(1, 0, 0).rotate(rotation);
+            let y = Vec3::new(0, 1, 0).rotate(rotation);
+            let z = Vec3::new(0, 0, 1).rotate(rotation);
+            let cross = Vec3::new(
+                x.y * y.z - x.z * y.y,
+                x.z * y.x - x.x * y.z,
+                x.x * y.y - x.y * y.x,
+            );
+            assert_eq!(z, cross, "rotation {}", rotation);
+        }
+    }
+
+    #[test]
+    fn test_conversions() {
+        let v = Vec2::<i32>::new(3, -4);
+        assert_eq!(Vec2::<i64>::new(3, -4), v.cast());
+        assert_eq!(Ok(Vec2::<isize>::new(3, -4)), v.try_cast());
+        assert!(v.try_cast::<usize>().is_err());
+        assert_eq!((3, -4), v.into());
+        assert_eq!(Vec3::new(1u8, 2, 3), (1, 2, 3).into());
+    }
+}